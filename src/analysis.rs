@@ -0,0 +1,68 @@
+use std::{collections::BTreeMap, ops::Range};
+
+use randomizer::{Seed, Settings};
+use serde::{Deserialize, Serialize};
+
+use crate::{items::filler_item_to_string, logic::compute_spheres, options::Options, placement::generate_assumed_fill_with};
+
+// half-open range of seeds, `end` is not included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeedRange {
+    pub start: Seed,
+    pub end: Seed,
+}
+
+impl From<SeedRange> for Range<Seed> {
+    fn from(range: SeedRange) -> Range<Seed> {
+        range.start..range.end
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SeedFailure {
+    pub seed: Seed,
+    pub message: String,
+}
+
+// aggregated placements of many seeds, counts are numbers of seeds
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SeedAnalysis {
+    pub seeds: u32,
+    pub generated: u32,
+    // item name -> check name -> count
+    pub item_locations: BTreeMap<String, BTreeMap<String, u32>>,
+    // check name -> item name -> count
+    pub check_items: BTreeMap<String, BTreeMap<String, u32>>,
+    // number of spheres until the goal -> count
+    pub sphere_counts: BTreeMap<usize, u32>,
+    pub failures: Vec<SeedFailure>,
+}
+
+// statistics of generate_assumed_fill_with over the seeds, they describe this crate's generator
+// and not the placements the desktop randomizer makes for the same seed numbers
+pub fn analyze_seeds(settings: &Settings, options: &Options, seeds: Range<Seed>) -> SeedAnalysis {
+    let mut analysis = SeedAnalysis::default();
+
+    for seed in seeds {
+        analysis.seeds += 1;
+        let placement = match generate_assumed_fill_with(settings, options, seed) {
+            Ok(placement) => placement,
+            Err(e) => {
+                analysis.failures.push(SeedFailure { seed, message: e.to_string() });
+                continue;
+            }
+        };
+        analysis.generated += 1;
+
+        for placed in &placement.checks {
+            let item = filler_item_to_string(placed.item);
+            *analysis.item_locations.entry(item.clone()).or_default().entry(placed.check.clone()).or_default() += 1;
+            *analysis.check_items.entry(placed.check.clone()).or_default().entry(item).or_default() += 1;
+        }
+
+        let spheres = compute_spheres(settings, options, &placement.check_map());
+        *analysis.sphere_counts.entry(spheres.len()).or_default() += 1;
+    }
+
+    analysis
+}
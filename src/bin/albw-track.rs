@@ -0,0 +1,272 @@
+// Command-line companion to the wasm module, built on the same core as Cartridge.
+//
+//     albw-track pools     --settings <file> [--seed <n>] [--json]
+//     albw-track reachable --settings <file> --inventory <file> [--seed <n>] [--json]
+//     albw-track goal      --settings <file> --inventory <file> [--seed <n>] [--json]
+//     albw-track generate  --settings <file> [--seed <n>] [--json]
+//     albw-track difficulty --settings <file> [--seed <n>] [--json]
+//     albw-track playthrough --settings <file> [--seed <n>] [--json]
+//     albw-track verify    --settings <file> --placement <file> [--json]
+//     albw-track validate  --settings <file> [--json]
+//
+// Every command also takes `--options <file>`, the crate options (plando pins
+// and so on) applied on top of the settings.
+//
+// generate, difficulty and playthrough use this crate's assumed fill, which
+// places items differently than the desktop randomizer does for the same seed.
+//
+// An inventory file is either a JSON array of item names or a tracker state
+// object (`{ "items": [...], "checked": [...] }`).
+
+use std::{fs, process::ExitCode};
+
+use albw_wasm::{compute_playthrough, filler_item_to_string, generate_assumed_fill_with, score_difficulty, verify_placement_with, Options, Placement, Seed, Settings, Tracker, TrackerState, Violation};
+use serde::Serialize;
+use serde_json::json;
+
+const USAGE: &str = "usage: albw-track <pools|reachable|goal|generate|difficulty|playthrough|verify|validate> --settings <file> [--options <file>] [--inventory <file>] [--placement <file>] [--seed <n>] [--json]";
+
+struct Args {
+    command: String,
+    settings: String,
+    options: Option<String>,
+    inventory: Option<String>,
+    placement: Option<String>,
+    seed: Seed,
+    json: bool,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let command = args.next().ok_or("missing command")?;
+    let mut settings = None;
+    let mut options = None;
+    let mut inventory = None;
+    let mut placement = None;
+    let mut seed = 0;
+    let mut json = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--settings" => settings = Some(args.next().ok_or("--settings needs a file")?),
+            "--options" => options = Some(args.next().ok_or("--options needs a file")?),
+            "--inventory" => inventory = Some(args.next().ok_or("--inventory needs a file")?),
+            "--placement" => placement = Some(args.next().ok_or("--placement needs a file")?),
+            "--seed" => {
+                let value = args.next().ok_or("--seed needs a number")?;
+                seed = value.parse().map_err(|_| format!("invalid seed {}", value))?;
+            }
+            "--json" => json = true,
+            other => return Err(format!("unknown argument {}", other)),
+        }
+    }
+
+    Ok(Args {
+        command,
+        settings: settings.ok_or("missing --settings")?,
+        options,
+        inventory,
+        placement,
+        seed,
+        json,
+    })
+}
+
+fn read(path: &str) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path, e))
+}
+
+fn load_settings(path: &str) -> Result<Settings, String> {
+    serde_json::from_str(&read(path)?).map_err(|e| format!("invalid settings in {}: {}", path, e))
+}
+
+fn load_options(path: Option<&str>) -> Result<Options, String> {
+    match path {
+        Some(path) => serde_json::from_str(&read(path)?).map_err(|e| format!("invalid options in {}: {}", path, e)),
+        None => Ok(Options::default()),
+    }
+}
+
+fn load_inventory(path: &str) -> Result<TrackerState, String> {
+    let mut value: serde_json::Value = serde_json::from_str(&read(path)?).map_err(|e| format!("invalid JSON in {}: {}", path, e))?;
+    if value.is_array() {
+        value = json!({ "items": value });
+    }
+    serde_json::from_value(value).map_err(|e| format!("invalid inventory in {}: {}", path, e))
+}
+
+fn load_placement(path: &str) -> Result<Placement, String> {
+    serde_json::from_str(&read(path)?).map_err(|e| format!("invalid placement in {}: {}", path, e))
+}
+
+fn print_json<T: Serialize>(value: &T) {
+    println!("{}", serde_json::to_string_pretty(value).unwrap());
+}
+
+fn describe_violation(violation: &Violation) -> String {
+    match violation {
+        Violation::UnknownCheck { check } => format!("unknown check {}", check),
+        Violation::DuplicateCheck { check } => format!("{} is placed more than once", check),
+        Violation::EmptyCheck { check } => format!("{} has no item", check),
+        Violation::ChangedFixedCheck { check, expected, found } => format!("{} must hold {}, found {}", check, expected, found),
+        Violation::MissingItem { item, count } => format!("{} missing {} time(s)", item, count),
+        Violation::ExtraItem { item, count } => format!("{} placed {} extra time(s)", item, count),
+        Violation::MisplacedDungeonItem { check, item } => format!("{} can't be at {} with these dungeon item options", item, check),
+        Violation::Unbeatable => "Yuganon can't be reached".to_string(),
+    }
+}
+
+// Ok(false) when the command ran but its answer is a failure, e.g. an invalid placement or an unbeatable playthrough
+fn run(args: Args) -> Result<bool, String> {
+    let settings = load_settings(&args.settings)?;
+    let options = load_options(args.options.as_deref())?;
+
+    match args.command.as_str() {
+        "pools" => {
+            let tracker = Tracker::with_options(settings, options, args.seed).map_err(|e| e.to_string())?;
+            let pools = tracker.pools();
+            if args.json {
+                print_json(&json!({
+                    "progression": pools.progression_names(),
+                    "trash": pools.trash_names(),
+                    "pool": pools.entries(),
+                }));
+            } else {
+                for (title, progression) in [("Progression", true), ("Trash", false)] {
+                    let entries: Vec<_> = pools.entries().into_iter().filter(|x| x.progression == progression).collect();
+                    println!("{} ({} items):", title, entries.iter().map(|x| x.count).sum::<usize>());
+                    for entry in entries {
+                        println!("  {:>3} x {}", entry.count, entry.name);
+                    }
+                }
+            }
+        }
+        "reachable" => {
+            let inventory = load_inventory(args.inventory.as_deref().ok_or("reachable needs --inventory")?)?;
+            let tracker = Tracker::with_options(settings, options, args.seed).map_err(|e| e.to_string())?;
+            let mut checks = tracker.available_checks(&inventory);
+            checks.sort();
+            if args.json {
+                print_json(&checks);
+            } else {
+                println!("{} reachable checks:", checks.len());
+                for check in checks {
+                    println!("  {}", check);
+                }
+            }
+        }
+        "goal" => {
+            let inventory = load_inventory(args.inventory.as_deref().ok_or("goal needs --inventory")?)?;
+            let tracker = Tracker::with_options(settings, options, args.seed).map_err(|e| e.to_string())?;
+            let status = tracker.goal_status(&inventory);
+            if args.json {
+                print_json(&status);
+            } else {
+                println!("Sages: {} ({} for Lorule Castle, {} for Yuganon)", status.sages, status.lorule_castle_sages, status.yuganon_sages);
+                println!("Lorule Castle: {}", if status.lorule_castle_open {"open"} else {"closed"});
+                println!("Triforce: {}", if status.triforce_reachable {"reachable"} else {"not reachable"});
+            }
+        }
+        "generate" => {
+            let placement = generate_assumed_fill_with(&settings, &options, args.seed).map_err(|e| e.to_string())?;
+            if args.json {
+                print_json(&placement);
+            } else {
+                println!("Seed {}:", placement.seed);
+                let width = placement.checks.iter().map(|x| x.check.len()).max().unwrap_or(0);
+                for placed in &placement.checks {
+                    println!("  {:<width$}  {}", placed.check, filler_item_to_string(placed.item), width = width);
+                }
+            }
+        }
+        "difficulty" => {
+            let placement = generate_assumed_fill_with(&settings, &options, args.seed).map_err(|e| e.to_string())?;
+            let difficulty = score_difficulty(&settings, &options, &placement).map_err(|e| e.to_string())?;
+            if args.json {
+                print_json(&difficulty);
+            } else {
+                println!("Seed {}: score {:.1}", placement.seed, difficulty.score);
+                println!("  spheres:                        {}", difficulty.spheres);
+                println!("  avg checks before key items:    {:.1}", difficulty.average_checks_before_key_items);
+                println!("  progression items in dungeons:  {}", difficulty.dungeon_progression_items);
+                println!("  progression items in overworld: {}", difficulty.overworld_progression_items);
+            }
+        }
+        "playthrough" => {
+            let placement = generate_assumed_fill_with(&settings, &options, args.seed).map_err(|e| e.to_string())?;
+            let playthrough = compute_playthrough(&settings, &options, &placement).map_err(|e| e.to_string())?;
+            if args.json {
+                print_json(&playthrough);
+            } else if !playthrough.beatable {
+                println!("Seed {} is not beatable", placement.seed);
+            } else {
+                for (sphere, pickups) in playthrough.spheres.iter().enumerate() {
+                    println!("Sphere {}:", sphere);
+                    for placed in pickups {
+                        println!("  {}: {}", placed.check, filler_item_to_string(placed.item));
+                    }
+                }
+            }
+            return Ok(playthrough.beatable);
+        }
+        "verify" => {
+            let placement = load_placement(args.placement.as_deref().ok_or("verify needs --placement")?)?;
+            let verification = verify_placement_with(&settings, &options, &placement).map_err(|e| e.to_string())?;
+            if args.json {
+                print_json(&verification);
+            } else if verification.valid {
+                println!("Placement for seed {} is valid", placement.seed);
+            } else {
+                println!("{} violations:", verification.violations.len());
+                for violation in &verification.violations {
+                    println!("  {}", describe_violation(violation));
+                }
+            }
+            return Ok(verification.valid);
+        }
+        "validate" => {
+            // settings already parsed above, computing the pools checks get_items and the options accept them
+            let tracker = Tracker::with_options(settings, options, args.seed).map_err(|e| e.to_string())?;
+            let pools = tracker.pools();
+            if args.json {
+                print_json(&json!({
+                    "valid": true,
+                    "progression": pools.progression.len(),
+                    "trash": pools.trash.len(),
+                }));
+            } else {
+                println!("{}: valid ({} progression items, {} trash items)", args.settings, pools.progression.len(), pools.trash.len());
+            }
+        }
+        other => return Err(format!("unknown command {}\n{}", other, USAGE)),
+    }
+    Ok(true)
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {}\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    let json = args.json;
+    let validate = args.command == "validate";
+
+    match run(args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) if json && validate => {
+            print_json(&json!({ "valid": false, "error": e }));
+            ExitCode::FAILURE
+        }
+        Err(e) if json => {
+            print_json(&json!({ "error": e }));
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use randomizer::Settings;
+use serde::Serialize;
+
+use crate::{
+    dungeons::Dungeon,
+    items::filler_item_to_string,
+    logic::compute_spheres,
+    options::Options,
+    placement::Placement,
+    pool::compute_placed_pools,
+    Error,
+};
+
+// weights of the difficulty score
+const SPHERE_WEIGHT: f64 = 5.0;
+const CHECKS_BEFORE_WEIGHT: f64 = 0.25;
+const DUNGEON_ITEM_WEIGHT: f64 = 1.0;
+
+// where a progression item sits in the playthrough
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KeyItemDepth {
+    pub item: String,
+    pub check: String,
+    pub sphere: usize,
+    pub checks_before: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Difficulty {
+    pub spheres: usize,
+    pub key_items: Vec<KeyItemDepth>,
+    pub average_checks_before_key_items: f64,
+    pub dungeon_progression_items: usize,
+    pub overworld_progression_items: usize,
+    pub score: f64,
+}
+
+pub fn score_difficulty(settings: &Settings, options: &Options, placement: &Placement) -> Result<Difficulty, Error> {
+    let progression_pool = compute_placed_pools(settings, options, placement.seed)?.progression;
+    let spheres = compute_spheres(settings, options, &placement.check_map());
+
+    let mut key_items = Vec::new();
+    let mut checks_before = 0;
+    for (sphere, checks) in spheres.iter().enumerate() {
+        for check in checks {
+            match placement.item_at(check) {
+                Some(item) if progression_pool.contains(&item) => key_items.push(KeyItemDepth {
+                    item: filler_item_to_string(item),
+                    check: check.clone(),
+                    sphere,
+                    checks_before,
+                }),
+                _ => {}
+            }
+        }
+        checks_before += checks.len();
+    }
+
+    let dungeon_progression_items = key_items.iter().filter(|x| Dungeon::of_check(&x.check).is_some()).count();
+    let overworld_progression_items = key_items.len() - dungeon_progression_items;
+    let average_checks_before_key_items = if key_items.is_empty() {
+        0.0
+    } else {
+        key_items.iter().map(|x| x.checks_before).sum::<usize>() as f64 / key_items.len() as f64
+    };
+
+    let score = spheres.len() as f64 * SPHERE_WEIGHT
+        + average_checks_before_key_items * CHECKS_BEFORE_WEIGHT
+        + dungeon_progression_items as f64 * DUNGEON_ITEM_WEIGHT;
+
+    Ok(Difficulty {
+        spheres: spheres.len(),
+        key_items,
+        average_checks_before_key_items,
+        dungeon_progression_items,
+        overworld_progression_items,
+        score,
+    })
+}
@@ -0,0 +1,159 @@
+use randomizer::filler_item::FillerItem;
+use serde::{Deserialize, Serialize};
+
+use crate::items::{filler_item_to_string, FILLER_ITEM_MAP};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Dungeon {
+    Eastern,
+    Gales,
+    Hera,
+    Dark,
+    Swamp,
+    Skull,
+    Thieves,
+    Ice,
+    Desert,
+    Turtle,
+    LoruleCastle,
+}
+
+impl Dungeon {
+    pub const ALL: [Dungeon; 11] = [
+        Dungeon::Eastern,
+        Dungeon::Gales,
+        Dungeon::Hera,
+        Dungeon::Dark,
+        Dungeon::Swamp,
+        Dungeon::Skull,
+        Dungeon::Thieves,
+        Dungeon::Ice,
+        Dungeon::Desert,
+        Dungeon::Turtle,
+        Dungeon::LoruleCastle,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Dungeon::Eastern => "Eastern Palace",
+            Dungeon::Gales => "House of Gales",
+            Dungeon::Hera => "Tower of Hera",
+            Dungeon::Dark => "Dark Palace",
+            Dungeon::Swamp => "Swamp Palace",
+            Dungeon::Skull => "Skull Woods",
+            Dungeon::Thieves => "Thieves' Hideout",
+            Dungeon::Ice => "Ice Ruins",
+            Dungeon::Desert => "Desert Palace",
+            Dungeon::Turtle => "Turtle Rock",
+            Dungeon::LoruleCastle => "Lorule Castle",
+        }
+    }
+
+    // world graph check names of a dungeon start with this tag, e.g. "[EP] (1F) Bow Chest"
+    pub fn check_prefix(self) -> &'static str {
+        match self {
+            Dungeon::Eastern => "[EP]",
+            Dungeon::Gales => "[HG]",
+            Dungeon::Hera => "[TH]",
+            Dungeon::Dark => "[PD]",
+            Dungeon::Swamp => "[SP]",
+            Dungeon::Skull => "[SW]",
+            Dungeon::Thieves => "[TT]",
+            Dungeon::Ice => "[IR]",
+            Dungeon::Desert => "[DP]",
+            Dungeon::Turtle => "[TR]",
+            Dungeon::LoruleCastle => "[LC]",
+        }
+    }
+
+    // FillerItem names of a dungeon's keys and compass start with this, e.g. "EasternKeySmall01"
+    pub fn item_prefix(self) -> &'static str {
+        match self {
+            Dungeon::Eastern => "Eastern",
+            Dungeon::Gales => "Gales",
+            Dungeon::Hera => "Hera",
+            Dungeon::Dark => "Dark",
+            Dungeon::Swamp => "Swamp",
+            Dungeon::Skull => "Skull",
+            Dungeon::Thieves => "Thieves",
+            Dungeon::Ice => "Ice",
+            Dungeon::Desert => "Desert",
+            Dungeon::Turtle => "Turtle",
+            Dungeon::LoruleCastle => "LoruleCastle",
+        }
+    }
+
+    // numbered small keys, in the order a key count hands them out
+    pub fn small_keys(self) -> Vec<FillerItem> {
+        all_dungeon_items()
+            .into_iter()
+            .filter(|item| Dungeon::of_item(*item) == Some(self) && is_small_key(*item))
+            .collect()
+    }
+
+    pub fn big_key(self) -> Option<FillerItem> {
+        self.item_named("KeyBig")
+    }
+
+    pub fn compass(self) -> Option<FillerItem> {
+        self.item_named("Compass")
+    }
+
+    fn item_named(self, suffix: &str) -> Option<FillerItem> {
+        FILLER_ITEM_MAP.get(&format!("{}{}", self.item_prefix(), suffix)).copied()
+    }
+
+    pub fn of_check(check: &str) -> Option<Dungeon> {
+        Dungeon::ALL.into_iter().find(|dungeon| check.starts_with(dungeon.check_prefix()))
+    }
+
+    // dungeon whose small key, big key or compass this is
+    pub fn of_item(item: FillerItem) -> Option<Dungeon> {
+        let name = filler_item_to_string(item);
+        Dungeon::ALL.into_iter().find(|dungeon| {
+            name.strip_prefix(dungeon.item_prefix())
+                .is_some_and(|rest| rest.starts_with("Key") || rest == "Compass")
+        })
+    }
+}
+
+// where a dungeon's small keys, big key and compass may be placed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DungeonItemMode {
+    OwnDungeon,
+    AnyDungeon,
+    Overworld,
+    #[default]
+    Anywhere,
+    // keysy: taken out of the pool and treated as always owned
+    Removed,
+}
+
+impl DungeonItemMode {
+    // whether the placement of dungeon items is limited to some checks
+    pub fn restricts(self) -> bool {
+        !matches!(self, DungeonItemMode::Anywhere | DungeonItemMode::Removed)
+    }
+
+    // whether an item of `dungeon` may go to a check in `check_dungeon`, None being the overworld
+    pub fn allows(self, dungeon: Dungeon, check_dungeon: Option<Dungeon>) -> bool {
+        match self {
+            DungeonItemMode::OwnDungeon => check_dungeon == Some(dungeon),
+            DungeonItemMode::AnyDungeon => check_dungeon.is_some(),
+            DungeonItemMode::Overworld => check_dungeon.is_none(),
+            DungeonItemMode::Anywhere | DungeonItemMode::Removed => true,
+        }
+    }
+}
+
+pub fn is_small_key(item: FillerItem) -> bool {
+    Dungeon::of_item(item).is_some() && filler_item_to_string(item).contains("KeySmall")
+}
+
+// every small key, big key and compass of the item table, sorted by name
+pub fn all_dungeon_items() -> Vec<FillerItem> {
+    let mut names: Vec<&String> = FILLER_ITEM_MAP.keys().filter(|name| Dungeon::of_item(FILLER_ITEM_MAP[*name]).is_some()).collect();
+    names.sort();
+    names.into_iter().map(|name| FILLER_ITEM_MAP[name]).collect()
+}
@@ -0,0 +1,37 @@
+use std::fmt;
+
+use serde::Serialize;
+use wasm_bindgen::JsValue;
+
+// errors surfaced to JS as plain objects tagged with their `kind`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind")]
+pub enum Error {
+    UnknownItem { name: String },
+    UnknownCheck { name: String },
+    InvalidSettings { message: String },
+    InvalidOptions { message: String },
+    InvalidArgument { message: String },
+    PlacementFailed { message: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnknownItem { name } => write!(f, "No FillerItem found for {}", name),
+            Error::UnknownCheck { name } => write!(f, "No check named {} in the world graph", name),
+            Error::InvalidSettings { message } => write!(f, "Invalid settings: {}", message),
+            Error::InvalidOptions { message } => write!(f, "Invalid options: {}", message),
+            Error::InvalidArgument { message } => write!(f, "Invalid argument: {}", message),
+            Error::PlacementFailed { message } => write!(f, "Placement failed: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for JsValue {
+    fn from(error: Error) -> JsValue {
+        serde_wasm_bindgen::to_value(&error).unwrap()
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::BTreeSet;
+
+use randomizer::{filler_item::FillerItem, Settings};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    logic::{find_reachable_check_names_in, CheckMap},
+    prizes::Prize,
+    Error,
+};
+
+// the number of sages in the game, which the world graph asks for at Lorule Castle
+pub const SAGES: usize = 7;
+
+// the item that is collected by beating Yuganon
+pub const GOAL_ITEM: FillerItem = FillerItem::Triforce;
+
+// sages needed to enter Lorule Castle and to fight Yuganon for the Triforce
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Goal {
+    pub lorule_castle_sages: usize,
+    pub yuganon_sages: usize,
+}
+
+impl Default for Goal {
+    fn default() -> Goal {
+        Goal { lorule_castle_sages: SAGES, yuganon_sages: SAGES }
+    }
+}
+
+// where the goal stands for an inventory
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GoalStatus {
+    // sages owned or collectible on the way
+    pub sages: usize,
+    pub lorule_castle_sages: usize,
+    pub yuganon_sages: usize,
+    pub lorule_castle_open: bool,
+    pub triforce_reachable: bool,
+}
+
+impl Goal {
+    pub fn validate(&self) -> Result<(), Error> {
+        for (what, count) in [("Lorule Castle", self.lorule_castle_sages), ("Yuganon", self.yuganon_sages)] {
+            if count > SAGES {
+                return Err(Error::InvalidOptions { message: format!("{} can't need {} sages, there are {}", what, count, SAGES) });
+            }
+        }
+        Ok(())
+    }
+
+    // Yuganon is inside Lorule Castle, so the castle's count applies to him too
+    fn sages_for_yuganon(&self) -> usize {
+        self.yuganon_sages.max(self.lorule_castle_sages)
+    }
+
+    fn sages(check_map: &CheckMap, reachable: &[String], items: &[FillerItem]) -> usize {
+        let collected = reachable.iter().filter_map(|check| check_map.get(check.as_str()).copied().flatten());
+        items.iter().copied().chain(collected)
+            .filter_map(Prize::of_item)
+            .filter(|prize| prize.is_sage())
+            .collect::<BTreeSet<Prize>>()
+            .len()
+    }
+
+    // reachable checks once the castle opens with this goal's count: the world graph wants all
+    // sages, so they are handed over when enough are found, and the Triforce waits for Yuganon's
+    pub fn reachable_check_names(&self, settings: &Settings, check_map: &CheckMap, items: &Vec<FillerItem>) -> Vec<String> {
+        let reachable = find_reachable_check_names_in(settings, check_map, items);
+        if *self == Goal::default() {
+            return reachable;
+        }
+
+        let sages = Goal::sages(check_map, &reachable, items);
+        let mut reachable = if sages >= self.lorule_castle_sages && sages < SAGES {
+            let mut items = items.clone();
+            items.extend(Prize::ALL.into_iter().filter(|prize| prize.is_sage()).map(Prize::item));
+            find_reachable_check_names_in(settings, check_map, &items)
+        } else {
+            reachable
+        };
+        if sages < self.sages_for_yuganon() {
+            reachable.retain(|check| check_map.get(check.as_str()) != Some(&Some(GOAL_ITEM)));
+        }
+        reachable
+    }
+
+    // the beatability check of every placement and analysis, the world graph alone asks for all sages
+    pub fn is_beatable(&self, settings: &Settings, check_map: &CheckMap, items: &Vec<FillerItem>) -> bool {
+        items.contains(&GOAL_ITEM)
+            || self.reachable_check_names(settings, check_map, items)
+                .iter()
+                .any(|check| check_map.get(check.as_str()) == Some(&Some(GOAL_ITEM)))
+    }
+
+    pub fn status(&self, settings: &Settings, check_map: &CheckMap, items: &Vec<FillerItem>) -> GoalStatus {
+        let reachable = find_reachable_check_names_in(settings, check_map, items);
+        let sages = Goal::sages(check_map, &reachable, items);
+        GoalStatus {
+            sages,
+            lorule_castle_sages: self.lorule_castle_sages,
+            yuganon_sages: self.yuganon_sages,
+            lorule_castle_open: sages >= self.lorule_castle_sages,
+            triforce_reachable: self.is_beatable(settings, check_map, items),
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use std::collections::HashSet;
+
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use randomizer::{filler::prefill_check_map, filler_item::FillerItem, world::build_world_graph, Settings};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    items::{filler_item_to_string, string_to_filler_item},
+    options::Options,
+    placement::Placement,
+    regions::{find_barren_regions, RegionMap},
+    required::find_required_items,
+    Error,
+};
+
+// mixed into the seed so hint rolls don't depend on how much rng the fill consumed
+const HINT_SEED_SALT: u64 = 0x6869_6e74;
+
+// where hint ghosts stand.
+// Reading them takes the Hint Glasses, which isn't checked here: hints are generated as if worn
+pub const HINT_GHOSTS: &[&str] = &[
+    "Lost Woods Maze 1",
+    "Lost Woods Maze 2",
+    "Lost Woods Maze 3",
+    "Lost Woods",
+    "Spectacle Rock",
+    "Tower of Hera Outside",
+    "Floating Island",
+    "Fire Cave",
+    "Moldorm Cave",
+    "Zora's Domain",
+    "Fortune-Teller (Hyrule)",
+    "Sanctuary",
+    "Graveyard (Hyrule)",
+    "Waterfall Cave",
+    "Kakariko Well",
+    "Shady Guy",
+    "Stylish Woman",
+    "Blacksmith Cave",
+    "Eastern Ruins Pegs",
+    "Eastern Ruins Cave",
+    "Eastern Ruins Entrance",
+    "Rupee Rush (Hyrule)",
+    "Cuccos",
+    "Southern Bridge",
+    "Southern Ruins",
+    "House of Gales Island",
+    "Hyrule Hotfoot",
+    "Letter",
+    "Street Merchant",
+    "Blacksmith",
+    "Graveyard Ledge",
+    "Desert East",
+    "Desert Center",
+    "Desert South West",
+    "Hyrule Castle Rocks",
+    "Witch's House",
+    "Skull Woods Cuccos",
+    "Treacherous Tower",
+    "Ice Ruins Outside",
+    "Graveyard (Lorule)",
+    "Dark Ruins North",
+    "Skull Woods South",
+    "Fortune's Choice",
+    "Vacant House",
+    "Misery Mire Ledge",
+    "Swamp Palace Outside Left",
+    "Turtle Bullied",
+    "Turtle Wall",
+    "Turtle Rock Outside",
+    "Dark Palace Outside",
+    "Cucco Dungeon",
+    "Lorule Castle Arena",
+    "Hilda's Study",
+];
+
+// checks worth a hint in every seed because they take a long time to clear
+pub const ALWAYS_HINT_CHECKS: &[&str] = &[
+    "Great Rupee Fairy",
+    "Octoball Derby",
+    "Treacherous Tower Intermediate",
+    "Hyrule Hotfoot (Second Race)",
+    "Rosso Rocks",
+    "Fortune's Choice",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HintKind {
+    Path,
+    Always,
+    Sometimes,
+    Barren,
+}
+
+// how many hints of each kind to generate, always hints cover every ALWAYS_HINT_CHECKS entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HintDistribution {
+    pub path: usize,
+    pub sometimes: usize,
+    pub barren: usize,
+}
+
+impl Default for HintDistribution {
+    fn default() -> Self {
+        HintDistribution { path: 4, sometimes: 6, barren: 3 }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Hint {
+    pub ghost: String,
+    pub kind: HintKind,
+    pub text: String,
+    pub region: Option<String>,
+    pub check: Option<String>,
+    pub item: Option<String>,
+}
+
+// what a read hint tells the player, stored in the tracker state
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HintFact {
+    ItemAtCheck {
+        #[serde(with = "crate::items::name")]
+        item: FillerItem,
+        check: String,
+    },
+    ItemInRegion {
+        #[serde(with = "crate::items::name")]
+        item: FillerItem,
+        region: String,
+    },
+    // the region holds something required to reach Yuganon
+    Path { region: String },
+    Barren { region: String },
+}
+
+impl Hint {
+    pub fn fact(&self) -> Option<HintFact> {
+        let item = self.item.as_ref().and_then(|x| string_to_filler_item(x).ok());
+        match (self.kind, &self.region, &self.check, item) {
+            (HintKind::Path, Some(region), _, _) => Some(HintFact::Path { region: region.clone() }),
+            (HintKind::Barren, Some(region), _, _) => Some(HintFact::Barren { region: region.clone() }),
+            (HintKind::Always | HintKind::Sometimes, _, Some(check), Some(item)) => Some(HintFact::ItemAtCheck { item, check: check.clone() }),
+            _ => None,
+        }
+    }
+}
+
+// "Bow01" -> "Bow", "RaviosBracelet02" -> "Ravios Bracelet"
+pub fn item_display_name(item: FillerItem) -> String {
+    let name = filler_item_to_string(item);
+    let mut out = String::new();
+    for c in name.trim_end_matches(|c: char| c.is_ascii_digit()).chars() {
+        if c.is_ascii_uppercase() && !out.is_empty() {
+            out.push(' ');
+        }
+        out.push(c);
+    }
+    out
+}
+
+fn region_of<'a>(regions: &'a RegionMap, check: &str) -> Option<&'a String> {
+    regions.iter().find(|(_, checks)| checks.iter().any(|x| x == check)).map(|(region, _)| region)
+}
+
+fn item_hint(kind: HintKind, check: &str, item: FillerItem) -> Hint {
+    let name = item_display_name(item);
+    Hint {
+        ghost: String::new(),
+        kind,
+        text: format!("They say that {} holds the {}.", check, name),
+        region: None,
+        check: Some(check.to_string()),
+        item: Some(filler_item_to_string(item)),
+    }
+}
+
+// hints for a placement assigned to ghost locations, deterministic for the placement's seed
+pub fn generate_hints(settings: &Settings, options: &Options, placement: &Placement, regions: &RegionMap, distribution: &HintDistribution) -> Result<Vec<Hint>, Error> {
+    let mut rng = StdRng::seed_from_u64(placement.seed as u64 ^ HINT_SEED_SALT);
+    let mut hinted_checks: HashSet<String> = HashSet::new();
+    let mut hints: Vec<Hint> = Vec::new();
+
+    // path: regions holding a required item, one hint per region
+    let mut path_regions: Vec<String> = find_required_items(settings, options, placement)?
+        .iter()
+        .filter_map(|required| region_of(regions, &required.check).cloned())
+        .collect();
+    path_regions.sort();
+    path_regions.dedup();
+    path_regions.shuffle(&mut rng);
+    for region in path_regions.into_iter().take(distribution.path) {
+        hints.push(Hint {
+            ghost: String::new(),
+            kind: HintKind::Path,
+            text: format!("They say that {} is on the path of the hero.", region),
+            region: Some(region),
+            check: None,
+            item: None,
+        });
+    }
+
+    // always: every long check, tests/hints.rs keeps the table in line with the world graph
+    for check in ALWAYS_HINT_CHECKS {
+        if let Some(item) = placement.item_at(check) {
+            hints.push(item_hint(HintKind::Always, check, item));
+            hinted_checks.insert(check.to_string());
+        }
+    }
+
+    // barren: regions without any required item
+    let mut barren: Vec<String> = find_barren_regions(settings, options, placement, regions)?
+        .into_iter()
+        .filter(|report| report.barren)
+        .map(|report| report.region)
+        .collect();
+    barren.shuffle(&mut rng);
+    for region in barren.into_iter().take(distribution.barren) {
+        hints.push(Hint {
+            ghost: String::new(),
+            kind: HintKind::Barren,
+            text: format!("They say that it is foolish to explore {}.", region),
+            region: Some(region),
+            check: None,
+            item: None,
+        });
+    }
+
+    // sometimes: random checks not hinted yet, leaving out the ones every seed fills the same way
+    let mut world_graph = build_world_graph();
+    let fixed_checks = prefill_check_map(&mut world_graph);
+    let mut candidates: Vec<&str> = placement.checks.iter()
+        .map(|placed| placed.check.as_str())
+        .filter(|check| !hinted_checks.contains(*check))
+        .filter(|check| matches!(fixed_checks.get(check), Some(None)))
+        .collect();
+    candidates.shuffle(&mut rng);
+    for check in candidates.into_iter().take(distribution.sometimes) {
+        hints.push(item_hint(HintKind::Sometimes, check, placement.item_at(check).unwrap()));
+    }
+
+    // ghosts: each hint gets its own ghost, the lowest priority hints are dropped if they run out
+    let mut ghosts: Vec<&str> = HINT_GHOSTS.to_vec();
+    ghosts.shuffle(&mut rng);
+    hints.truncate(ghosts.len());
+    for (hint, ghost) in hints.iter_mut().zip(ghosts) {
+        hint.ghost = ghost.to_string();
+    }
+    hints.sort_by(|a, b| a.ghost.cmp(&b.ghost));
+
+    Ok(hints)
+}
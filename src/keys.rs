@@ -0,0 +1,80 @@
+use std::collections::BTreeSet;
+
+use randomizer::filler_item::FillerItem;
+use serde::Serialize;
+
+use crate::{
+    dungeons::{is_small_key, Dungeon, DungeonItemMode},
+    tracker::{Tracker, TrackerState},
+};
+
+// small key situation of one dungeon for a tracker state
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DungeonKeys {
+    pub dungeon: Dungeon,
+    pub keys_found: usize,
+    pub keys_total: usize,
+    // fewest keys that open every check of the dungeon the state's other items can reach
+    pub keys_needed: usize,
+    // checks of the dungeon reachable with the keys found, checked or not
+    pub reachable_checks: usize,
+    // a found key could go into a later door than the logic assumes and strand checks it counts as reachable
+    pub soft_lock_risk: bool,
+}
+
+// small keys found for a dungeon: the recorded count if there is one, else the copies among the items
+pub fn small_keys_found(state: &TrackerState, dungeon: Dungeon) -> usize {
+    let count = state.small_keys.get(&dungeon).copied().unwrap_or_else(|| {
+        state.items.iter().filter(|item| is_small_key(**item) && Dungeon::of_item(**item) == Some(dungeon)).count()
+    });
+    count.min(dungeon.small_keys().len())
+}
+
+// the state's items with every dungeon's small keys replaced by its first numbered copies,
+// so only the count of keys matters to the logic
+pub fn key_inventory(state: &TrackerState) -> Vec<FillerItem> {
+    let mut items: Vec<FillerItem> = state.items.iter().copied().filter(|item| !is_small_key(*item)).collect();
+    for dungeon in Dungeon::ALL {
+        items.extend(dungeon.small_keys().into_iter().take(small_keys_found(state, dungeon)));
+    }
+    items
+}
+
+// The logic only counts keys, so a dungeon is modeled as a row of doors: door j opens the checks
+// reachable with j keys but not with j - 1. Which door a key really goes into is up to the player,
+// so when the found keys cover a door that opens checks and a later door opens more, a key spent on
+// the later door strands the checks behind the earlier one.
+pub fn find_dungeon_keys(tracker: &Tracker, state: &TrackerState) -> Vec<DungeonKeys> {
+    let keysy = tracker.options().dungeon_items == DungeonItemMode::Removed;
+    let others: Vec<FillerItem> = state.items.iter().copied().filter(|item| !is_small_key(*item)).collect();
+
+    // reachable checks with every dungeon holding its first `count` keys, one search per count for all dungeons
+    let most_keys = Dungeon::ALL.into_iter().map(|dungeon| dungeon.small_keys().len()).max().unwrap_or(0);
+    let reachable_by_count: Vec<BTreeSet<String>> = (0..=most_keys)
+        .map(|count| {
+            let mut items = others.clone();
+            for dungeon in Dungeon::ALL {
+                items.extend(dungeon.small_keys().into_iter().take(count));
+            }
+            tracker.reachable_checks_for(state, &items).into_iter().collect()
+        })
+        .collect();
+
+    Dungeon::ALL
+        .into_iter()
+        .map(|dungeon| {
+            let keys_total = dungeon.small_keys().len();
+            let keys_found = if keysy { keys_total } else { small_keys_found(state, dungeon) };
+            let opened: Vec<usize> = reachable_by_count[..=keys_total]
+                .iter()
+                .map(|reachable| reachable.iter().filter(|check| Dungeon::of_check(check) == Some(dungeon)).count())
+                .collect();
+
+            let keys_needed = (0..=keys_total).find(|count| opened[*count] == opened[keys_total]).unwrap_or(keys_total);
+            let opens_before = opened[keys_found] > opened[0];
+            let opens_after = opened[keys_total] > opened[keys_found];
+            let soft_lock_risk = !keysy && keys_found > 0 && opens_before && opens_after;
+            DungeonKeys { dungeon, keys_found, keys_total, keys_needed, reachable_checks: opened[keys_found], soft_lock_risk }
+        })
+        .collect()
+}
@@ -0,0 +1,63 @@
+use std::collections::{HashMap, HashSet};
+
+use randomizer::{filler::{assumed_search, prefill_check_map}, filler_item::FillerItem, world::build_world_graph, Settings};
+
+use crate::{goal::GOAL_ITEM, options::Options};
+
+// item at every check by name, `None` while the check is empty
+pub type CheckMap = HashMap<&'static str, Option<FillerItem>>;
+
+// names of all checks reachable with the given items, without any placement
+pub fn find_reachable_check_names(settings: &Settings, obtained_items: &Vec<FillerItem>) -> Vec<String> {
+    let mut world_graph = build_world_graph();
+    let mut check_map = prefill_check_map(&mut world_graph);
+    let reachable_checks = assumed_search(&mut world_graph, obtained_items, &mut check_map, settings); //find_reachable_checks(loc_map, &everything, &mut check_map); //
+    reachable_checks.iter().map(|check| check.name.to_string()).collect()
+}
+
+// names of all checks reachable with the given items, collecting whatever is placed in check_map on the way
+pub fn find_reachable_check_names_in(settings: &Settings, check_map: &CheckMap, obtained_items: &Vec<FillerItem>) -> Vec<String> {
+    let mut world_graph = build_world_graph();
+    let mut check_map = check_map.clone();
+    let reachable_checks = assumed_search(&mut world_graph, obtained_items, &mut check_map, settings);
+    reachable_checks.iter().map(|check| check.name.to_string()).collect()
+}
+
+// checks of a placement grouped by the sphere they first become reachable in, starting from the
+// items the options grant, stops once the goal is collected or nothing new opens up
+pub fn compute_spheres(settings: &Settings, options: &Options, check_map: &CheckMap) -> Vec<Vec<String>> {
+    let mut world_graph = build_world_graph();
+    let fixed = prefill_check_map(&mut world_graph);
+    let mut spheres: Vec<Vec<String>> = Vec::new();
+    let mut collected: HashSet<String> = HashSet::new();
+    let mut owned: Vec<FillerItem> = Vec::new();
+
+    while !owned.contains(&GOAL_ITEM) {
+        let mut sphere: Vec<String> = options.goal.reachable_check_names(settings, &fixed, &options.inventory(&owned))
+            .into_iter()
+            .filter(|check| !collected.contains(check))
+            .collect();
+        if sphere.is_empty() {
+            break;
+        }
+        sphere.sort();
+        sphere.dedup();
+
+        for check in &sphere {
+            if let Some(Some(item)) = check_map.get(check.as_str()) {
+                owned.push(*item);
+            }
+            collected.insert(check.clone());
+        }
+        spheres.push(sphere);
+    }
+    spheres
+}
+
+// every check name of the world graph, sorted
+pub fn all_check_names() -> Vec<String> {
+    let mut world_graph = build_world_graph();
+    let mut names: Vec<String> = prefill_check_map(&mut world_graph).keys().map(|check| check.to_string()).collect();
+    names.sort();
+    names
+}
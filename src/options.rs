@@ -0,0 +1,153 @@
+use std::collections::{BTreeSet, HashSet};
+
+use randomizer::{filler::prefill_check_map, filler_item::FillerItem, world::build_world_graph, Settings};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    dungeons::{all_dungeon_items, Dungeon, DungeonItemMode},
+    goal::Goal,
+    items::filler_item_to_string,
+    placement::PlacedItem,
+    pool::{ItemPools, PoolEdit},
+    Error,
+};
+
+// trash dropped, cheapest first, when pool edits leave more items than there are checks to hold them
+const SURPLUS_FILLER: &[&str] = &["RupeeGreen", "RupeeBlue", "RupeeRed", "RupeePurple", "RupeeSilver", "RupeeGold"];
+
+// options of this crate on top of the upstream randomizer Settings
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Options {
+    // check -> item pairs placed before filling, the items are taken out of the pools
+    pub plando: Vec<PlacedItem>,
+    // checks that only ever get trash, both when generating and in the tracker
+    pub exclusions: BTreeSet<String>,
+    // changes to the pool get_items produces, in order
+    pub pool_edits: Vec<PoolEdit>,
+    // granted before the first check, taken out of the pools when they hold them
+    #[serde(with = "crate::items::names")]
+    pub starting_items: Vec<FillerItem>,
+    // where small keys, big keys and compasses go
+    pub dungeon_items: DungeonItemMode,
+    // sages needed for Lorule Castle and Yuganon
+    pub goal: Goal,
+}
+
+impl Options {
+    pub fn validate(&self) -> Result<(), Error> {
+        self.goal.validate()?;
+        let mut world_graph = build_world_graph();
+        let check_map = prefill_check_map(&mut world_graph);
+
+        let mut pinned: HashSet<&str> = HashSet::new();
+        for pin in &self.plando {
+            match check_map.get(pin.check.as_str()) {
+                None => return Err(Error::UnknownCheck { name: pin.check.clone() }),
+                Some(Some(_)) => return Err(Error::InvalidOptions { message: format!("{} is fixed by the world graph and can't be pinned", pin.check) }),
+                Some(None) => {}
+            }
+            if !pinned.insert(pin.check.as_str()) {
+                return Err(Error::InvalidOptions { message: format!("{} is pinned more than once", pin.check) });
+            }
+        }
+        for check in &self.exclusions {
+            match check_map.get(check.as_str()) {
+                None => return Err(Error::UnknownCheck { name: check.clone() }),
+                Some(Some(_)) => return Err(Error::InvalidOptions { message: format!("{} is fixed by the world graph and can't be excluded", check) }),
+                Some(None) => {}
+            }
+        }
+        Ok(())
+    }
+
+    pub fn is_excluded(&self, check: &str) -> bool {
+        self.exclusions.contains(check)
+    }
+
+    // whether the dungeon item mode lets the item go to the check
+    pub fn allows(&self, item: FillerItem, check: &str) -> bool {
+        Dungeon::of_item(item).is_none_or(|dungeon| self.dungeon_items.allows(dungeon, Dungeon::of_check(check)))
+    }
+
+    // whether the placed items can differ from what get_items produced
+    pub fn edits_pool(&self) -> bool {
+        !self.pool_edits.is_empty() || !self.starting_items.is_empty() || self.dungeon_items == DungeonItemMode::Removed
+    }
+
+    // items owned from the start: the starting items, plus every dungeon item when they are removed
+    pub fn granted_items(&self) -> Vec<FillerItem> {
+        let mut granted = self.starting_items.clone();
+        if self.dungeon_items == DungeonItemMode::Removed {
+            granted.extend(all_dungeon_items());
+        }
+        granted
+    }
+
+    // the inventory assumed_search gets: the granted items plus whatever was found
+    pub fn inventory(&self, items: &[FillerItem]) -> Vec<FillerItem> {
+        self.granted_items().into_iter().chain(items.iter().copied()).collect()
+    }
+
+    // the items a placement holds: the pool get_items produced with the edits applied, the
+    // starting items taken out and surplus rupees dropped, which together must still be able to beat the seed
+    pub fn edit_pools(&self, settings: &Settings, pools: &mut ItemPools) -> Result<(), Error> {
+        for edit in &self.pool_edits {
+            edit.apply(pools)?;
+        }
+        for item in &self.starting_items {
+            pools.remove(*item);
+        }
+        if self.dungeon_items == DungeonItemMode::Removed {
+            pools.progression.retain(|item| Dungeon::of_item(*item).is_none());
+            pools.trash.retain(|item| Dungeon::of_item(*item).is_none());
+        }
+        if self.edits_pool() {
+            let mut world_graph = build_world_graph();
+            let check_map = prefill_check_map(&mut world_graph);
+            self.drop_surplus_filler(pools, check_map.values().filter(|item| item.is_none()).count())?;
+            if !self.goal.is_beatable(settings, &check_map, &self.inventory(&pools.progression)) {
+                return Err(Error::InvalidOptions { message: "The edited item pool can't beat the seed".to_string() });
+            }
+        }
+        Ok(())
+    }
+
+    // rupees make room for added items, the ones plando pins are kept so the pins still find them
+    fn drop_surplus_filler(&self, pools: &mut ItemPools, open_checks: usize) -> Result<(), Error> {
+        let mut surplus = (pools.progression.len() + pools.trash.len()).saturating_sub(open_checks);
+        let pinned = |item: &FillerItem| self.plando.iter().any(|pin| pin.item == *item);
+        for prefix in SURPLUS_FILLER {
+            while surplus > 0 {
+                match pools.trash.iter().rposition(|item| filler_item_to_string(*item).starts_with(prefix) && !pinned(item)) {
+                    Some(index) => {
+                        pools.trash.remove(index);
+                        surplus -= 1;
+                    }
+                    None => break,
+                }
+            }
+        }
+        if surplus > 0 {
+            return Err(Error::InvalidOptions { message: format!("The edited item pool holds {} more items than there are checks", surplus) });
+        }
+        Ok(())
+    }
+
+    // the items left to fill: the edited pools without what the options place themselves
+    pub fn apply_to_pools(&self, settings: &Settings, pools: &mut ItemPools) -> Result<(), Error> {
+        self.edit_pools(settings, pools)?;
+        for pin in &self.plando {
+            if self.is_excluded(&pin.check) && pools.progression.contains(&pin.item) {
+                return Err(Error::InvalidOptions { message: format!("{} is excluded but pinned to progression item {}", pin.check, filler_item_to_string(pin.item)) });
+            }
+            if !self.allows(pin.item, &pin.check) {
+                return Err(Error::InvalidOptions { message: format!("{} can't be pinned to {} with dungeon items {:?}", filler_item_to_string(pin.item), pin.check, self.dungeon_items) });
+            }
+            if !pools.remove(pin.item) {
+                return Err(Error::InvalidOptions { message: format!("{} is pinned but not in the item pool", filler_item_to_string(pin.item)) });
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use randomizer::{filler::{assumed_search, get_items, prefill_check_map}, filler_item::FillerItem, world::build_world_graph, Seed, Settings};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    dungeons::Dungeon,
+    items::filler_item_to_string,
+    logic::CheckMap,
+    options::Options,
+    pool::ItemPools,
+    Error,
+};
+
+// what checks get once the trash pool runs out
+pub const PADDING_ITEM: FillerItem = FillerItem::RupeeGreen;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlacedItem {
+    pub check: String,
+    #[serde(with = "crate::items::name")]
+    pub item: FillerItem,
+}
+
+// item for every filled check of a seed, sorted by check name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Placement {
+    pub seed: Seed,
+    pub checks: Vec<PlacedItem>,
+}
+
+impl Placement {
+    pub fn item_at(&self, check: &str) -> Option<FillerItem> {
+        self.checks.iter().find(|x| x.check == check).map(|x| x.item)
+    }
+
+    pub fn check_map(&self) -> CheckMap {
+        let mut world_graph = build_world_graph();
+        let mut check_map = prefill_check_map(&mut world_graph);
+        for placed in &self.checks {
+            if let Some((check, _)) = check_map.get_key_value(placed.check.as_str()) {
+                let check = *check;
+                check_map.insert(check, Some(placed.item));
+            }
+        }
+        check_map
+    }
+
+    fn from_check_map(seed: Seed, check_map: &CheckMap) -> Placement {
+        let mut checks: Vec<PlacedItem> = check_map
+            .iter()
+            .filter_map(|(check, item)| item.map(|item| PlacedItem { check: check.to_string(), item }))
+            .collect();
+        checks.sort_by(|a, b| a.check.cmp(&b.check));
+        Placement { seed, checks }
+    }
+}
+
+// empty checks among `checks`, sorted so the rng picks the same one regardless of hash order
+fn empty_checks<'a>(check_map: &CheckMap, checks: impl Iterator<Item = &'a str>) -> Vec<&'static str> {
+    let mut empty: Vec<&'static str> = checks
+        .filter_map(|name| check_map.get_key_value(name))
+        .filter(|(_, item)| item.is_none())
+        .map(|(check, _)| *check)
+        .collect();
+    empty.sort();
+    empty.dedup();
+    empty
+}
+
+// This crate's own generator, not the desktop randomizer's filler: upstream doesn't expose a fill
+// that takes pinned checks, so a seed number gives a different placement here than in the
+// desktop randomizer. Use it for plando and statistics of this generator, not for spoilers of real seeds.
+pub fn generate_assumed_fill(settings: &Settings, seed: Seed) -> Result<Placement, Error> {
+    generate_assumed_fill_with(settings, &Options::default(), seed)
+}
+
+// assumed fill: each progression item goes to a check reachable with everything still unplaced,
+// excluded checks are left for the trash and dungeon items stay where the mode allows
+pub fn generate_assumed_fill_with(settings: &Settings, options: &Options, seed: Seed) -> Result<Placement, Error> {
+    options.validate()?;
+
+    let mut rng = StdRng::seed_from_u64(seed as u64);
+    let (progression, trash) = get_items(settings, &mut rng);
+    let mut pools = ItemPools { progression, trash };
+    options.apply_to_pools(settings, &mut pools)?;
+    let ItemPools { progression: mut progression_pool, trash: mut trash_pool } = pools;
+
+    let mut world_graph = build_world_graph();
+    let mut check_map = prefill_check_map(&mut world_graph);
+
+    for pin in &options.plando {
+        if let Some(item) = check_map.get_mut(pin.check.as_str()) {
+            *item = Some(pin.item);
+        }
+    }
+    if !options.plando.is_empty() && !options.goal.is_beatable(settings, &check_map, &options.inventory(&progression_pool)) {
+        return Err(Error::PlacementFailed { message: "Plando pins make the seed unbeatable".to_string() });
+    }
+
+    progression_pool.shuffle(&mut rng);
+    if options.dungeon_items.restricts() {
+        // dungeon items have the fewest checks to go to, so they are placed first
+        progression_pool.sort_by_key(|item| Dungeon::of_item(*item).is_some());
+    }
+    while let Some(item) = progression_pool.pop() {
+        let reachable = assumed_search(&mut world_graph, &options.inventory(&progression_pool), &mut check_map, settings);
+        let reachable_names: Vec<String> = reachable.iter().map(|check| check.name.to_string()).collect();
+        let mut empty = empty_checks(&check_map, reachable_names.iter().map(|x| x.as_str()));
+        empty.retain(|check| !options.is_excluded(check) && options.allows(item, check));
+        if empty.is_empty() {
+            return Err(Error::PlacementFailed { message: format!("No reachable empty check left for {}", filler_item_to_string(item)) });
+        }
+        check_map.insert(empty[rng.gen_range(0..empty.len())], Some(item));
+    }
+
+    let all_checks: Vec<&'static str> = check_map.keys().copied().collect();
+    let mut empty = empty_checks(&check_map, all_checks.into_iter());
+    if empty.len() < trash_pool.len() {
+        return Err(Error::PlacementFailed { message: format!("{} trash items left for {} empty checks", trash_pool.len(), empty.len()) });
+    }
+    empty.shuffle(&mut rng);
+    trash_pool.shuffle(&mut rng);
+    if options.dungeon_items.restricts() {
+        // trash dungeon items (compasses mostly) take the first allowed check before the rest is dealt out
+        let (dungeon_trash, rest): (Vec<FillerItem>, Vec<FillerItem>) = trash_pool.into_iter().partition(|item| Dungeon::of_item(*item).is_some());
+        for item in dungeon_trash {
+            let index = empty.iter().position(|check| options.allows(item, check)).ok_or_else(|| Error::PlacementFailed {
+                message: format!("No empty check left for {}", filler_item_to_string(item)),
+            })?;
+            check_map.insert(empty.remove(index), Some(item));
+        }
+        trash_pool = rest;
+    }
+    // pool edits, starting items and removed dungeon items can leave fewer items than checks, the rest is padded;
+    // Options::edit_pools already dropped rupees when they left more
+    if options.edits_pool() {
+        trash_pool.resize(empty.len(), PADDING_ITEM);
+    }
+    for (check, item) in empty.into_iter().zip(trash_pool) {
+        check_map.insert(check, Some(item));
+    }
+
+    if !options.goal.is_beatable(settings, &check_map, &options.granted_items()) {
+        return Err(Error::PlacementFailed { message: "Generated placement is not beatable".to_string() });
+    }
+
+    Ok(Placement::from_check_map(seed, &check_map))
+}
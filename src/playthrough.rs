@@ -0,0 +1,55 @@
+use randomizer::Settings;
+use serde::Serialize;
+
+use crate::{
+    goal::GOAL_ITEM,
+    logic::compute_spheres,
+    options::Options,
+    placement::{PlacedItem, Placement},
+    pool::compute_placed_pools,
+    Error,
+};
+
+// minimal walk-through of a placement, the progression pickups needed per sphere up to the goal
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Playthrough {
+    pub beatable: bool,
+    pub spheres: Vec<Vec<PlacedItem>>,
+}
+
+// the options' starting items and removed dungeon items are owned from sphere 0 on
+pub fn compute_playthrough(settings: &Settings, options: &Options, placement: &Placement) -> Result<Playthrough, Error> {
+    let progression_pool = compute_placed_pools(settings, options, placement.seed)?.progression;
+    let granted = options.granted_items();
+    let mut check_map = placement.check_map();
+    if !options.goal.is_beatable(settings, &check_map, &granted) {
+        return Ok(Playthrough { beatable: false, spheres: Vec::new() });
+    }
+
+    // prune pickups the goal doesn't depend on, latest spheres first so earlier copies are kept
+    for check in compute_spheres(settings, options, &check_map).iter().rev().flatten() {
+        let item = match check_map.get(check.as_str()) {
+            Some(Some(item)) if progression_pool.contains(item) => *item,
+            _ => continue,
+        };
+        *check_map.get_mut(check.as_str()).unwrap() = None;
+        if !options.goal.is_beatable(settings, &check_map, &granted) {
+            *check_map.get_mut(check.as_str()).unwrap() = Some(item);
+        }
+    }
+
+    let spheres = compute_spheres(settings, options, &check_map)
+        .into_iter()
+        .map(|sphere| {
+            sphere.into_iter()
+                .filter_map(|check| match check_map.get(check.as_str()) {
+                    Some(Some(item)) if progression_pool.contains(item) || *item == GOAL_ITEM => Some(PlacedItem { check, item: *item }),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        })
+        .filter(|sphere| !sphere.is_empty())
+        .collect();
+
+    Ok(Playthrough { beatable: true, spheres })
+}
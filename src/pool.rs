@@ -0,0 +1,144 @@
+use rand::{rngs::StdRng, SeedableRng};
+use randomizer::{filler::get_items, filler_item::FillerItem, Seed, Settings};
+use serde::{Deserialize, Serialize};
+
+use crate::{dungeons::Dungeon, items::filler_item_to_string, options::Options, Error};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PoolEntry {
+    pub name: String,
+    pub count: usize,
+    pub progression: bool,
+    // set for small keys, big keys and compasses
+    pub dungeon: Option<Dungeon>,
+}
+
+// one change to the pool get_items produced, applied in order
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PoolEdit {
+    // extra copies, as progression or as trash
+    Add {
+        #[serde(with = "crate::items::names")]
+        items: Vec<FillerItem>,
+        #[serde(default)]
+        progression: bool,
+    },
+    // one copy of each, from whichever half holds it
+    Remove {
+        #[serde(with = "crate::items::names")]
+        items: Vec<FillerItem>,
+    },
+    // one copy of `item` swapped for `with`, which stays in the same half
+    Replace {
+        #[serde(with = "crate::items::name")]
+        item: FillerItem,
+        #[serde(with = "crate::items::name")]
+        with: FillerItem,
+    },
+}
+
+impl PoolEdit {
+    pub fn apply(&self, pools: &mut ItemPools) -> Result<(), Error> {
+        let missing = |item: &FillerItem| Error::InvalidOptions { message: format!("{} can't be edited out of the item pool, it isn't in it", filler_item_to_string(*item)) };
+        match self {
+            PoolEdit::Add { items, progression: true } => pools.progression.extend(items),
+            PoolEdit::Add { items, progression: false } => pools.trash.extend(items),
+            PoolEdit::Remove { items } => {
+                for item in items {
+                    if !pools.remove(*item) {
+                        return Err(missing(item));
+                    }
+                }
+            }
+            PoolEdit::Replace { item, with } => {
+                if !pools.replace(*item, *with) {
+                    return Err(missing(item));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// progression and trash halves of the item pool, computed from a single get_items call
+#[derive(Debug, Clone)]
+pub struct ItemPools {
+    pub progression: Vec<FillerItem>,
+    pub trash: Vec<FillerItem>,
+}
+
+impl ItemPools {
+    // takes one copy of the item out of whichever half holds it
+    pub fn remove(&mut self, item: FillerItem) -> bool {
+        for pool in [&mut self.progression, &mut self.trash] {
+            if let Some(index) = pool.iter().position(|x| *x == item) {
+                pool.remove(index);
+                return true;
+            }
+        }
+        false
+    }
+
+    // swaps one copy of the item in place, keeping it in the same half
+    pub fn replace(&mut self, item: FillerItem, with: FillerItem) -> bool {
+        for pool in [&mut self.progression, &mut self.trash] {
+            if let Some(slot) = pool.iter_mut().find(|x| **x == item) {
+                *slot = with;
+                return true;
+            }
+        }
+        false
+    }
+
+    pub fn progression_names(&self) -> Vec<String> {
+        pool_to_names(&self.progression)
+    }
+
+    pub fn trash_names(&self) -> Vec<String> {
+        pool_to_names(&self.trash)
+    }
+
+    // combined pool of both halves, one entry per item name with the number of copies
+    pub fn entries(&self) -> Vec<PoolEntry> {
+        let mut entries : Vec<PoolEntry> = Vec::new();
+        for (pool, progression) in [(&self.progression, true), (&self.trash, false)] {
+            for item in pool {
+                let name = filler_item_to_string(*item);
+                match entries.iter_mut().find(|e| e.name == name && e.progression == progression) {
+                    Some(entry) => entry.count += 1,
+                    None => entries.push(PoolEntry { name, count: 1, progression, dungeon: Dungeon::of_item(*item) }),
+                }
+            }
+        }
+        entries.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+        entries
+    }
+}
+
+pub fn compute_item_pools(settings: &Settings, seed: Seed) -> ItemPools {
+    let mut rng = StdRng::seed_from_u64(seed as u64);
+    let (progression, trash) = get_items(settings, &mut rng);
+    ItemPools { progression, trash }
+}
+
+// pools as reported to the tracker and filled by generate_assumed_fill_with, after the options are applied
+pub fn compute_item_pools_with(settings: &Settings, options: &Options, seed: Seed) -> Result<ItemPools, Error> {
+    let mut pools = compute_item_pools(settings, seed);
+    options.apply_to_pools(settings, &mut pools)?;
+    Ok(pools)
+}
+
+// items a placement generated with the options holds, the plando pins included
+pub fn compute_placed_pools(settings: &Settings, options: &Options, seed: Seed) -> Result<ItemPools, Error> {
+    let mut pools = compute_item_pools(settings, seed);
+    options.edit_pools(settings, &mut pools)?;
+    Ok(pools)
+}
+
+// convert a pool to sorted item names using filler_item_to_string
+pub fn pool_to_names(pool: &[FillerItem]) -> Vec<String> {
+    let mut names : Vec<String> = pool.iter().map(|x| filler_item_to_string(*x)).collect();
+    names.sort_by(|a, b| a.to_lowercase().cmp(&b.to_lowercase()));
+    names
+}
@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+
+use randomizer::{filler::prefill_check_map, filler_item::FillerItem, world::build_world_graph, Settings};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    dungeons::Dungeon,
+    goal::Goal,
+    logic::CheckMap,
+};
+
+// pendants and sage portraits dungeons reward, named like their FillerItems
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Prize {
+    PendantOfCourage,
+    PendantOfWisdom,
+    PendantOfPower,
+    SageGulley,
+    SageOren,
+    SageSeres,
+    SageOsfala,
+    SageRosso,
+    SageIrene,
+    SageImpa,
+}
+
+impl Prize {
+    pub const ALL: [Prize; 10] = [
+        Prize::PendantOfCourage,
+        Prize::PendantOfWisdom,
+        Prize::PendantOfPower,
+        Prize::SageGulley,
+        Prize::SageOren,
+        Prize::SageSeres,
+        Prize::SageOsfala,
+        Prize::SageRosso,
+        Prize::SageIrene,
+        Prize::SageImpa,
+    ];
+
+    pub fn item(self) -> FillerItem {
+        match self {
+            Prize::PendantOfCourage => FillerItem::PendantOfCourage,
+            Prize::PendantOfWisdom => FillerItem::PendantOfWisdom,
+            Prize::PendantOfPower => FillerItem::PendantOfPower,
+            Prize::SageGulley => FillerItem::SageGulley,
+            Prize::SageOren => FillerItem::SageOren,
+            Prize::SageSeres => FillerItem::SageSeres,
+            Prize::SageOsfala => FillerItem::SageOsfala,
+            Prize::SageRosso => FillerItem::SageRosso,
+            Prize::SageIrene => FillerItem::SageIrene,
+            Prize::SageImpa => FillerItem::SageImpa,
+        }
+    }
+
+    pub fn of_item(item: FillerItem) -> Option<Prize> {
+        Prize::ALL.into_iter().find(|prize| prize.item() == item)
+    }
+
+    pub fn is_sage(self) -> bool {
+        !matches!(self, Prize::PendantOfCourage | Prize::PendantOfWisdom | Prize::PendantOfPower)
+    }
+
+    // what the dungeon rewards in the unrandomized game, Lorule Castle has no prize
+    pub fn vanilla(dungeon: Dungeon) -> Option<Prize> {
+        match dungeon {
+            Dungeon::Eastern => Some(Prize::PendantOfCourage),
+            Dungeon::Gales => Some(Prize::PendantOfWisdom),
+            Dungeon::Hera => Some(Prize::PendantOfPower),
+            Dungeon::Dark => Some(Prize::SageGulley),
+            Dungeon::Swamp => Some(Prize::SageOren),
+            Dungeon::Skull => Some(Prize::SageSeres),
+            Dungeon::Thieves => Some(Prize::SageOsfala),
+            Dungeon::Ice => Some(Prize::SageRosso),
+            Dungeon::Desert => Some(Prize::SageIrene),
+            Dungeon::Turtle => Some(Prize::SageImpa),
+            Dungeon::LoruleCastle => None,
+        }
+    }
+}
+
+// dungeon -> prize, as far as the player has found out
+pub type PrizeMap = BTreeMap<Dungeon, Prize>;
+
+// the check the world graph fills with each dungeon's vanilla prize, collecting it
+// is what beating the dungeon gives in the logic
+pub fn prize_checks() -> BTreeMap<Dungeon, &'static str> {
+    let mut world_graph = build_world_graph();
+    let fixed = prefill_check_map(&mut world_graph);
+    Dungeon::ALL
+        .into_iter()
+        .filter_map(|dungeon| {
+            let prize = Prize::vanilla(dungeon)?.item();
+            let mut checks: Vec<&'static str> = fixed.iter().filter(|(_, item)| **item == Some(prize)).map(|(check, _)| *check).collect();
+            checks.sort();
+            checks.first().map(|check| (dungeon, *check))
+        })
+        .collect()
+}
+
+// puts the recorded prizes on the prize checks of their dungeons, swapping: the prize a recorded
+// one displaces moves to the unrecorded dungeon that held the recorded one, so no prize is there twice
+pub fn apply_prizes(check_map: &mut CheckMap, prizes: &PrizeMap) {
+    let checks = prize_checks();
+    for (dungeon, prize) in prizes {
+        if let Some(check) = checks.get(dungeon) {
+            let displaced = check_map.get(check).copied().flatten();
+            let holder = checks
+                .iter()
+                .filter(|(other, _)| !prizes.contains_key(*other))
+                .find(|(_, other_check)| check_map.get(*other_check).copied().flatten() == Some(prize.item()));
+            if let Some((_, holder_check)) = holder {
+                check_map.insert(*holder_check, displaced);
+            }
+            check_map.insert(*check, Some(prize.item()));
+        }
+    }
+}
+
+// dungeons whose prize the goal can't do without, assuming every item of the inventory
+pub fn find_required_dungeons(settings: &Settings, goal: &Goal, check_map: &CheckMap, items: &Vec<FillerItem>) -> Vec<Dungeon> {
+    prize_checks()
+        .into_iter()
+        .filter(|(_, check)| {
+            let mut without_prize = check_map.clone();
+            without_prize.insert(*check, None);
+            !goal.is_beatable(settings, &without_prize, items)
+        })
+        .map(|(dungeon, _)| dungeon)
+        .collect()
+}
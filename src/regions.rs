@@ -0,0 +1,58 @@
+use std::collections::BTreeMap;
+
+use randomizer::Settings;
+use serde::Serialize;
+
+use crate::{
+    dungeons::Dungeon,
+    logic::all_check_names,
+    options::Options,
+    placement::Placement,
+    required::{find_required_items, RequiredItem},
+    Error,
+};
+
+// region name -> names of the checks in it
+pub type RegionMap = BTreeMap<String, Vec<String>>;
+
+// region for checks outside of any dungeon in default_regions()
+pub const OVERWORLD: &str = "Overworld";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RegionReport {
+    pub region: String,
+    // no required progression item in any of the region's checks ("foolish")
+    pub barren: bool,
+    pub required_items: Vec<RequiredItem>,
+}
+
+// every dungeon as its own region, the remaining checks grouped as the overworld
+pub fn default_regions() -> RegionMap {
+    let mut regions = RegionMap::new();
+    for check in all_check_names() {
+        let region = Dungeon::of_check(&check).map_or(OVERWORLD, |dungeon| dungeon.name());
+        regions.entry(region.to_string()).or_default().push(check);
+    }
+    regions
+}
+
+// every check of the grouping must exist in the world graph
+pub fn validate_regions(regions: &RegionMap) -> Result<(), Error> {
+    let known = all_check_names();
+    for check in regions.values().flatten() {
+        if known.binary_search(check).is_err() {
+            return Err(Error::UnknownCheck { name: check.clone() });
+        }
+    }
+    Ok(())
+}
+
+pub fn find_barren_regions(settings: &Settings, options: &Options, placement: &Placement, regions: &RegionMap) -> Result<Vec<RegionReport>, Error> {
+    validate_regions(regions)?;
+    let required = find_required_items(settings, options, placement)?;
+
+    Ok(regions.iter().map(|(region, checks)| {
+        let required_items: Vec<RequiredItem> = required.iter().filter(|x| checks.contains(&x.check)).cloned().collect();
+        RegionReport { region: region.clone(), barren: required_items.is_empty(), required_items }
+    }).collect())
+}
@@ -0,0 +1,36 @@
+use randomizer::Settings;
+use serde::Serialize;
+
+use crate::{
+    items::filler_item_to_string,
+    options::Options,
+    placement::Placement,
+    pool::compute_placed_pools,
+    Error,
+};
+
+// a progression item without which Yuganon can't be reached, the "Way of the Hero"
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RequiredItem {
+    pub item: String,
+    pub check: String,
+}
+
+// progression items whose removal from the placement makes the game unbeatable, sorted by check
+pub fn find_required_items(settings: &Settings, options: &Options, placement: &Placement) -> Result<Vec<RequiredItem>, Error> {
+    let progression_pool = compute_placed_pools(settings, options, placement.seed)?.progression;
+    let granted = options.granted_items();
+    let check_map = placement.check_map();
+
+    Ok(placement.checks.iter()
+        .filter(|placed| progression_pool.contains(&placed.item))
+        .filter(|placed| {
+            let mut without = check_map.clone();
+            if let Some(item) = without.get_mut(placed.check.as_str()) {
+                *item = None;
+            }
+            !options.goal.is_beatable(settings, &without, &granted)
+        })
+        .map(|placed| RequiredItem { item: filler_item_to_string(placed.item), check: placed.check.clone() })
+        .collect())
+}
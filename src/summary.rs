@@ -0,0 +1,58 @@
+use randomizer::filler_item::FillerItem;
+use serde::Serialize;
+
+use crate::{
+    dungeons::{Dungeon, DungeonItemMode},
+    items::filler_item_to_string,
+    keys::find_dungeon_keys,
+    logic::all_check_names,
+    tracker::{Tracker, TrackerState},
+};
+
+// one dungeon panel of a tracker
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DungeonSummary {
+    pub dungeon: Dungeon,
+    pub total_checks: usize,
+    pub checked: usize,
+    // unchecked checks of the dungeon reachable with the state
+    pub reachable: usize,
+    pub keys_found: usize,
+    // fewest keys that open every check of the dungeon the state's other items can reach
+    pub keys_needed: usize,
+    // None for dungeons without a big key
+    pub big_key: Option<bool>,
+    pub compass: Option<bool>,
+    // item of the prize recorded in the state, None until it's recorded
+    pub prize: Option<String>,
+}
+
+// per dungeon counts for a tracker state
+pub fn summarize_dungeons(tracker: &Tracker, state: &TrackerState) -> Vec<DungeonSummary> {
+    let keysy = tracker.options().dungeon_items == DungeonItemMode::Removed;
+    let owned = |item: Option<FillerItem>| item.map(|item| keysy || state.items.contains(&item));
+
+    let keys = find_dungeon_keys(tracker, state);
+    let checks = all_check_names();
+    let available = tracker.available_checks(state);
+
+    Dungeon::ALL
+        .into_iter()
+        .zip(keys)
+        .map(|(dungeon, keys)| {
+            let in_dungeon = |check: &&String| Dungeon::of_check(check) == Some(dungeon);
+
+            DungeonSummary {
+                dungeon,
+                total_checks: checks.iter().filter(in_dungeon).count(),
+                checked: state.checked.iter().filter(in_dungeon).count(),
+                reachable: available.iter().filter(in_dungeon).count(),
+                keys_found: keys.keys_found,
+                keys_needed: keys.keys_needed,
+                big_key: owned(dungeon.big_key()),
+                compass: owned(dungeon.compass()),
+                prize: state.prizes.get(&dungeon).map(|prize| filler_item_to_string(prize.item())),
+            }
+        })
+        .collect()
+}
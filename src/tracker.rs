@@ -0,0 +1,175 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use randomizer::{filler::prefill_check_map, filler_item::FillerItem, world::build_world_graph, Seed, Settings};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    dungeons::Dungeon,
+    hints::HintFact,
+    keys::key_inventory,
+    goal::GoalStatus,
+    logic::CheckMap,
+    options::Options,
+    pool::{compute_item_pools_with, ItemPools, PoolEdit},
+    prizes::{apply_prizes, find_required_dungeons, PrizeMap},
+    regions::{default_regions, validate_regions, RegionMap},
+    Error,
+};
+
+// what the player has found so far, serialized with item names so it can be stored as JSON
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TrackerState {
+    #[serde(default, with = "crate::items::names")]
+    pub items: Vec<FillerItem>,
+    #[serde(default)]
+    pub checked: BTreeSet<String>,
+    #[serde(default)]
+    pub hints: Vec<HintFact>,
+    // small keys found per dungeon, counted instead of naming the numbered copies
+    #[serde(default)]
+    pub small_keys: BTreeMap<Dungeon, usize>,
+    // prizes learned in game, e.g. from the map or a dungeon's entrance
+    #[serde(default)]
+    pub prizes: PrizeMap,
+}
+
+// what the recorded hints say about a check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Relevance {
+    Unknown,
+    Relevant,
+    Irrelevant,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AvailableCheck {
+    pub check: String,
+    pub relevance: Relevance,
+    // excluded by the options, so it never holds progression
+    pub junk_only: bool,
+}
+
+// settings, options and seed of a tracked game, with its item pools computed once
+pub struct Tracker {
+    settings: Settings,
+    options: Options,
+    seed: Seed,
+    pools: ItemPools,
+    regions: RegionMap,
+}
+
+impl Tracker {
+    pub fn new(settings: Settings, seed: Seed) -> Tracker {
+        Tracker::with_options(settings, Options::default(), seed).expect("default options are valid")
+    }
+
+    pub fn with_options(settings: Settings, options: Options, seed: Seed) -> Result<Tracker, Error> {
+        options.validate()?;
+        let pools = compute_item_pools_with(&settings, &options, seed)?;
+        Ok(Tracker { settings, options, seed, pools, regions: default_regions() })
+    }
+
+    // appends pool edits to the options, leaving the tracker as it was if the edited pool is invalid
+    pub fn edit_pool(&mut self, edits: Vec<PoolEdit>) -> Result<(), Error> {
+        let mut options = self.options.clone();
+        options.pool_edits.extend(edits);
+        self.pools = compute_item_pools_with(&self.settings, &options, self.seed)?;
+        self.options = options;
+        Ok(())
+    }
+
+    // regions that hint facts refer to, default_regions() unless replaced
+    pub fn regions(&self) -> &RegionMap {
+        &self.regions
+    }
+
+    pub fn set_regions(&mut self, regions: RegionMap) -> Result<(), Error> {
+        validate_regions(&regions)?;
+        self.regions = regions;
+        Ok(())
+    }
+
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+
+    pub fn seed(&self) -> Seed {
+        self.seed
+    }
+
+    pub fn pools(&self) -> &ItemPools {
+        &self.pools
+    }
+
+    // reachable with the found items and the starting items
+    pub fn reachable_checks(&self, items: &Vec<FillerItem>) -> Vec<String> {
+        self.reachable_checks_for(&TrackerState::default(), items)
+    }
+
+    // the world graph's fixed checks, with the prizes recorded in the state on their dungeons
+    pub fn check_map(&self, state: &TrackerState) -> CheckMap {
+        let mut world_graph = build_world_graph();
+        let mut check_map = prefill_check_map(&mut world_graph);
+        apply_prizes(&mut check_map, &state.prizes);
+        check_map
+    }
+
+    // reachable with the items and the starting items, collecting the state's prizes on the way
+    // and opening Lorule Castle at the goal's sage count
+    pub fn reachable_checks_for(&self, state: &TrackerState, items: &Vec<FillerItem>) -> Vec<String> {
+        self.options.goal.reachable_check_names(&self.settings, &self.check_map(state), &self.options.inventory(items))
+    }
+
+    // dungeons whose prize the goal needs, with every item of the pool assumed found
+    pub fn required_dungeons(&self, state: &TrackerState) -> Vec<Dungeon> {
+        find_required_dungeons(&self.settings, &self.options.goal, &self.check_map(state), &self.options.inventory(&self.pools.progression))
+    }
+
+    // sages found, whether Lorule Castle is open and the Triforce reachable with the state
+    pub fn goal_status(&self, state: &TrackerState) -> GoalStatus {
+        self.options.goal.status(&self.settings, &self.check_map(state), &self.options.inventory(&key_inventory(state)))
+    }
+
+    // reachable checks that haven't been checked yet, small keys only count by number
+    pub fn available_checks(&self, state: &TrackerState) -> Vec<String> {
+        self.reachable_checks_for(state, &key_inventory(state))
+            .into_iter()
+            .filter(|check| !state.checked.contains(check))
+            .collect()
+    }
+
+    // a check is relevant if a hint points at it or its region, irrelevant if it holds a hinted
+    // trash item or lies in a barren region
+    pub fn relevance(&self, state: &TrackerState, check: &str) -> Relevance {
+        let in_region = |region: &String| self.regions.get(region).is_some_and(|checks| checks.iter().any(|x| x == check));
+        let mut relevance = Relevance::Unknown;
+        for hint in &state.hints {
+            match hint {
+                HintFact::ItemAtCheck { check: hinted, item } if hinted == check => {
+                    return if self.pools.progression.contains(item) { Relevance::Relevant } else { Relevance::Irrelevant };
+                }
+                HintFact::ItemInRegion { region, .. } | HintFact::Path { region } if in_region(region) => return Relevance::Relevant,
+                HintFact::Barren { region } if in_region(region) => relevance = Relevance::Irrelevant,
+                _ => {}
+            }
+        }
+        relevance
+    }
+
+    // available checks flagged with what the recorded hints and the exclusions say about them
+    pub fn annotated_checks(&self, state: &TrackerState) -> Vec<AvailableCheck> {
+        self.available_checks(state)
+            .into_iter()
+            .map(|check| AvailableCheck {
+                relevance: self.relevance(state, &check),
+                junk_only: self.options.is_excluded(&check),
+                check,
+            })
+            .collect()
+    }
+}
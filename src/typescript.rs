@@ -0,0 +1,989 @@
+use randomizer::{filler_item::FillerItem, settings::LogicMode, Settings};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Map, Value};
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    analysis::{SeedAnalysis, SeedFailure, SeedRange},
+    difficulty::{Difficulty, KeyItemDepth},
+    dungeons::{Dungeon, DungeonItemMode},
+    goal::{Goal, GoalStatus},
+    hints::{Hint, HintDistribution, HintFact, HintKind},
+    items::FILLER_ITEM_MAP,
+    keys::DungeonKeys,
+    options::Options,
+    placement::{PlacedItem, Placement},
+    playthrough::Playthrough,
+    regions::RegionReport,
+    required::RequiredItem,
+    summary::DungeonSummary,
+    tracker::{AvailableCheck, Relevance, TrackerState},
+    verify::{Verification, Violation},
+    worker::{Command, Reply, Request, Response},
+    pool::PoolEdit,
+    prizes::Prize,
+    Error, PoolEntry,
+};
+
+// typed handles for the JsValues crossing the boundary, so the generated .d.ts doesn't say `any`
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "Settings")]
+    pub type JsSettings;
+
+    #[wasm_bindgen(typescript_type = "ItemId[]")]
+    pub type ItemIdArray;
+
+    #[wasm_bindgen(typescript_type = "CheckName[]")]
+    pub type CheckNameArray;
+
+    #[wasm_bindgen(typescript_type = "PoolEntry[]")]
+    pub type PoolEntryArray;
+
+    #[wasm_bindgen(typescript_type = "PoolEdit[]")]
+    pub type PoolEditArray;
+
+    #[wasm_bindgen(typescript_type = "Options")]
+    pub type JsOptions;
+
+    #[wasm_bindgen(typescript_type = "Placement")]
+    pub type JsPlacement;
+
+    #[wasm_bindgen(typescript_type = "Playthrough")]
+    pub type JsPlaythrough;
+
+    #[wasm_bindgen(typescript_type = "Verification")]
+    pub type JsVerification;
+
+    #[wasm_bindgen(typescript_type = "Difficulty")]
+    pub type JsDifficulty;
+
+    #[wasm_bindgen(typescript_type = "RequiredItem[]")]
+    pub type RequiredItemArray;
+
+    #[wasm_bindgen(typescript_type = "RegionMap")]
+    pub type JsRegionMap;
+
+    #[wasm_bindgen(typescript_type = "RegionReport[]")]
+    pub type RegionReportArray;
+
+    #[wasm_bindgen(typescript_type = "HintDistribution")]
+    pub type JsHintDistribution;
+
+    #[wasm_bindgen(typescript_type = "Hint[]")]
+    pub type HintArray;
+
+    #[wasm_bindgen(typescript_type = "TrackerState")]
+    pub type JsTrackerState;
+
+    #[wasm_bindgen(typescript_type = "AvailableCheck[]")]
+    pub type AvailableCheckArray;
+
+    #[wasm_bindgen(typescript_type = "Dungeon[]")]
+    pub type DungeonArray;
+
+    #[wasm_bindgen(typescript_type = "DungeonKeys[]")]
+    pub type DungeonKeysArray;
+
+    #[wasm_bindgen(typescript_type = "DungeonSummary[]")]
+    pub type DungeonSummaryArray;
+
+    #[wasm_bindgen(typescript_type = "GoalStatus")]
+    pub type JsGoalStatus;
+
+    #[wasm_bindgen(typescript_type = "SeedRange")]
+    pub type JsSeedRange;
+
+    #[wasm_bindgen(typescript_type = "SeedAnalysis")]
+    pub type JsSeedAnalysis;
+
+    #[wasm_bindgen(typescript_type = "WorkerRequest")]
+    pub type WorkerRequest;
+
+    #[wasm_bindgen(typescript_type = "WorkerResponse")]
+    pub type WorkerResponse;
+}
+
+// logic modes in declaration order: `next` matches on the mode, so an upstream mode added to
+// the enum doesn't compile until it's linked in here
+fn next_logic_mode(mode: &LogicMode) -> Option<LogicMode> {
+    match mode {
+        LogicMode::Normal => Some(LogicMode::Hard),
+        LogicMode::Hard => Some(LogicMode::GlitchBasic),
+        LogicMode::GlitchBasic => Some(LogicMode::GlitchAdvanced),
+        LogicMode::GlitchAdvanced => Some(LogicMode::GlitchHell),
+        LogicMode::GlitchHell => Some(LogicMode::NoLogic),
+        LogicMode::NoLogic => None,
+    }
+}
+
+fn ts_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "unknown[]",
+        Value::Object(_) => "object",
+    }
+}
+
+fn sample<T: Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap()
+}
+
+fn fields(sample: &Value) -> &Map<String, Value> {
+    match sample {
+        Value::Object(map) => map,
+        other => panic!("Expected an object sample, got {}", other),
+    }
+}
+
+fn field_type(field: &str, value: &Value, overrides: &[(&str, &str)]) -> String {
+    match overrides.iter().find(|(f, _)| *f == field) {
+        Some((_, ty)) => ty.to_string(),
+        None => ts_type(value).to_string(),
+    }
+}
+
+// whether a field may be left out of a sample, decides between `field:` and `field?:`
+type Optional = fn(&Value, &str) -> bool;
+
+// output types always carry every field
+fn required(_: &Value, _: &str) -> bool {
+    false
+}
+
+// input types may leave out the fields deserializing fills in, the #[serde(default)] ones
+fn defaulted<T: DeserializeOwned>(sample: &Value, field: &str) -> bool {
+    let mut without = sample.clone();
+    if let Value::Object(map) = &mut without {
+        map.remove(field);
+    }
+    serde_json::from_value::<T>(without).is_ok()
+}
+
+fn member(field: &str, sample: &Value, optional: Optional, ty: String) -> String {
+    format!("{}{}: {}", field, if optional(sample, field) { "?" } else { "" }, ty)
+}
+
+// interface with the fields of a serialized sample, `overrides` replaces the inferred type of a field
+fn interface_of(name: &str, sample: &Value, optional: Optional, overrides: &[(&str, &str)]) -> String {
+    let mut out = format!("export interface {} {{\n", name);
+    for (field, value) in fields(sample) {
+        out += &format!("    {};\n", member(field, sample, optional, field_type(field, value, overrides)));
+    }
+    out + "}\n"
+}
+
+fn interface<T: Serialize>(name: &str, instance: &T, optional: Optional, overrides: &[(&str, &str)]) -> String {
+    interface_of(name, &sample(instance), optional, overrides)
+}
+
+// union of objects discriminated by their `tag` field, one member per serialized sample
+fn tagged_union(name: &str, tag: &str, samples: &[Value], optional: Optional, overrides: &[(&str, &str)]) -> String {
+    let members: Vec<String> = samples.iter().map(|sample| {
+        let members: Vec<String> = fields(sample).iter().map(|(field, value)| {
+            let ty = if field == tag { value.to_string() } else { field_type(field, value, overrides) };
+            member(field, sample, optional, ty)
+        }).collect();
+        format!("    | {{ {} }}", members.join("; "))
+    }).collect();
+    format!("export type {} =\n{};\n", name, members.join("\n"))
+}
+
+fn literal_union(name: &str, literals: &[String]) -> String {
+    let members: Vec<String> = literals.iter().map(|x| format!("    | \"{}\"", x)).collect();
+    format!("export type {} =\n{};\n", name, members.join("\n"))
+}
+
+// every variant of an enum, from `first` on. `next` matches on the previous variant, so a variant
+// added to the enum doesn't compile until it's linked into the chain, and then gets declared
+fn variants<T>(first: T, next: fn(&T) -> Option<T>) -> Vec<T> {
+    std::iter::successors(Some(first), next).collect()
+}
+
+fn samples<T: Serialize>(variants: &[T]) -> Vec<Value> {
+    variants.iter().map(sample).collect()
+}
+
+// serde names of a unit-only enum's variants
+fn names<T: Serialize>(variants: &[T]) -> Vec<String> {
+    variants.iter().map(|x| sample(x).as_str().unwrap().to_string()).collect()
+}
+
+// the upstream settings this crate reads, read off a default Settings so a renamed field
+// doesn't compile. The rest of the upstream settings passes through untyped
+fn settings_interfaces() -> [String; 2] {
+    let logic = Settings::default().logic;
+    let logic = json!({
+        "mode": logic.mode,
+        "super_items": logic.super_items,
+        "skip_trials": logic.skip_trials,
+        "lampless": logic.lampless,
+        "swordless_mode": logic.swordless_mode,
+    });
+    let settings = json!({ "logic": logic });
+    let open = |interface: String, index: &str| interface.replace("\n}\n", &format!("\n    [{}: string]: unknown;\n}}\n", index));
+    [
+        open(interface_of("Logic", &logic, required, &[("mode", "LogicMode")]), "option"),
+        open(interface_of("Settings", &settings, required, &[("logic", "Logic")]), "section"),
+    ]
+}
+
+fn dungeons() -> Vec<String> {
+    names(&Dungeon::ALL)
+}
+
+fn prizes() -> Vec<String> {
+    names(&Prize::ALL)
+}
+
+fn next_error(error: &Error) -> Option<Error> {
+    let message = String::new();
+    match error {
+        Error::UnknownItem { .. } => Some(Error::UnknownCheck { name: String::new() }),
+        Error::UnknownCheck { .. } => Some(Error::InvalidSettings { message }),
+        Error::InvalidSettings { .. } => Some(Error::InvalidOptions { message }),
+        Error::InvalidOptions { .. } => Some(Error::InvalidArgument { message }),
+        Error::InvalidArgument { .. } => Some(Error::PlacementFailed { message }),
+        Error::PlacementFailed { .. } => None,
+    }
+}
+
+fn next_pool_edit(edit: &PoolEdit) -> Option<PoolEdit> {
+    match edit {
+        PoolEdit::Add { .. } => Some(PoolEdit::Remove { items: Vec::new() }),
+        PoolEdit::Remove { .. } => Some(PoolEdit::Replace { item: FillerItem::Bow01, with: FillerItem::Bow01 }),
+        PoolEdit::Replace { .. } => None,
+    }
+}
+
+fn next_dungeon_item_mode(mode: &DungeonItemMode) -> Option<DungeonItemMode> {
+    match mode {
+        DungeonItemMode::OwnDungeon => Some(DungeonItemMode::AnyDungeon),
+        DungeonItemMode::AnyDungeon => Some(DungeonItemMode::Overworld),
+        DungeonItemMode::Overworld => Some(DungeonItemMode::Anywhere),
+        DungeonItemMode::Anywhere => Some(DungeonItemMode::Removed),
+        DungeonItemMode::Removed => None,
+    }
+}
+
+fn next_violation(violation: &Violation) -> Option<Violation> {
+    let check = String::new();
+    let item = String::new();
+    match violation {
+        Violation::UnknownCheck { .. } => Some(Violation::DuplicateCheck { check }),
+        Violation::DuplicateCheck { .. } => Some(Violation::EmptyCheck { check }),
+        Violation::EmptyCheck { .. } => Some(Violation::ChangedFixedCheck { check, expected: String::new(), found: String::new() }),
+        Violation::ChangedFixedCheck { .. } => Some(Violation::MissingItem { item, count: 0 }),
+        Violation::MissingItem { .. } => Some(Violation::ExtraItem { item, count: 0 }),
+        Violation::ExtraItem { .. } => Some(Violation::MisplacedDungeonItem { check, item }),
+        Violation::MisplacedDungeonItem { .. } => Some(Violation::Unbeatable),
+        Violation::Unbeatable => None,
+    }
+}
+
+fn next_hint_kind(kind: &HintKind) -> Option<HintKind> {
+    match kind {
+        HintKind::Path => Some(HintKind::Always),
+        HintKind::Always => Some(HintKind::Sometimes),
+        HintKind::Sometimes => Some(HintKind::Barren),
+        HintKind::Barren => None,
+    }
+}
+
+fn next_hint_fact(fact: &HintFact) -> Option<HintFact> {
+    let region = String::new();
+    match fact {
+        HintFact::ItemAtCheck { .. } => Some(HintFact::ItemInRegion { item: FillerItem::Bow01, region }),
+        HintFact::ItemInRegion { .. } => Some(HintFact::Path { region }),
+        HintFact::Path { .. } => Some(HintFact::Barren { region }),
+        HintFact::Barren { .. } => None,
+    }
+}
+
+fn next_relevance(relevance: &Relevance) -> Option<Relevance> {
+    match relevance {
+        Relevance::Unknown => Some(Relevance::Relevant),
+        Relevance::Relevant => Some(Relevance::Irrelevant),
+        Relevance::Irrelevant => None,
+    }
+}
+
+fn next_command(command: &Command) -> Option<Command> {
+    match command {
+        Command::Init { .. } => Some(Command::ProgressionItems),
+        Command::ProgressionItems => Some(Command::TrashItems),
+        Command::TrashItems => Some(Command::ItemPool),
+        Command::ItemPool => Some(Command::AvailableChecks { items: Vec::new() }),
+        Command::AvailableChecks { .. } => Some(Command::GenerateAssumedFill),
+        Command::GenerateAssumedFill => Some(Command::AnalyzeSeeds { seeds: SeedRange { start: 0, end: 0 } }),
+        Command::AnalyzeSeeds { .. } => Some(Command::AnnotatedChecks { state: TrackerState::default() }),
+        Command::AnnotatedChecks { .. } => Some(Command::DungeonKeys { state: TrackerState::default() }),
+        Command::DungeonKeys { .. } => Some(Command::DungeonSummaries { state: TrackerState::default() }),
+        Command::DungeonSummaries { .. } => Some(Command::RequiredDungeons { state: TrackerState::default() }),
+        Command::RequiredDungeons { .. } => Some(Command::GoalStatus { state: TrackerState::default() }),
+        Command::GoalStatus { .. } => None,
+    }
+}
+
+fn next_reply(reply: &Reply) -> Option<Reply> {
+    match reply {
+        Reply::Ready { .. } => Some(Reply::ProgressionItems { items: Vec::new() }),
+        Reply::ProgressionItems { .. } => Some(Reply::TrashItems { items: Vec::new() }),
+        Reply::TrashItems { .. } => Some(Reply::ItemPool { pool: Vec::new() }),
+        Reply::ItemPool { .. } => Some(Reply::AvailableChecks { checks: Vec::new() }),
+        Reply::AvailableChecks { .. } => Some(Reply::Placement { placement: Placement { seed: 0, checks: Vec::new() } }),
+        Reply::Placement { .. } => Some(Reply::Analysis { analysis: SeedAnalysis::default() }),
+        Reply::Analysis { .. } => Some(Reply::AnnotatedChecks { annotated_checks: Vec::new() }),
+        Reply::AnnotatedChecks { .. } => Some(Reply::DungeonKeys { keys: Vec::new() }),
+        Reply::DungeonKeys { .. } => Some(Reply::DungeonSummaries { summaries: Vec::new() }),
+        Reply::DungeonSummaries { .. } => Some(Reply::RequiredDungeons { dungeons: Vec::new() }),
+        Reply::RequiredDungeons { .. } => Some(Reply::GoalStatus { status: GoalStatus {
+            sages: 0,
+            lorule_castle_sages: 0,
+            yuganon_sages: 0,
+            lorule_castle_open: false,
+            triforce_reachable: false,
+        } }),
+        Reply::GoalStatus { .. } => Some(Reply::Error { error: Error::InvalidArgument { message: String::new() } }),
+        Reply::Error { .. } => None,
+    }
+}
+
+fn request_samples() -> Vec<Value> {
+    let init = Command::Init { settings: Box::default(), seed: 0, options: Options::default() };
+    variants(init, next_command)
+        .into_iter()
+        .map(|command| sample(&Request { id: 0, command }))
+        .collect()
+}
+
+fn response_samples() -> Vec<Value> {
+    variants(Reply::Ready { seed: 0 }, next_reply)
+        .into_iter()
+        .map(|reply| sample(&Response { id: 0, reply }))
+        .collect()
+}
+
+// types of the fields shared by worker requests and responses
+const WORKER_FIELDS: &[(&str, &str)] = &[
+    ("settings", "Settings"),
+    ("options", "Options"),
+    ("items", "ItemId[]"),
+    ("checks", "CheckName[]"),
+    ("pool", "PoolEntry[]"),
+    ("placement", "Placement"),
+    ("seeds", "SeedRange"),
+    ("analysis", "SeedAnalysis"),
+    ("state", "TrackerState"),
+    ("annotated_checks", "AvailableCheck[]"),
+    ("keys", "DungeonKeys[]"),
+    ("summaries", "DungeonSummary[]"),
+    ("dungeons", "Dungeon[]"),
+    ("status", "GoalStatus"),
+    ("error", "AlbwError"),
+];
+
+pub fn declarations() -> String {
+    let [logic, settings] = settings_interfaces();
+    let mut item_ids: Vec<String> = FILLER_ITEM_MAP.keys().cloned().collect();
+    item_ids.sort_by(|a, b| a.to_lowercase().cmp(&b.to_lowercase()));
+
+    let sections = [
+        literal_union("LogicMode", &names(&variants(LogicMode::Normal, next_logic_mode))),
+        logic,
+        settings,
+        literal_union("ItemId", &item_ids),
+        "export type CheckName = string;\n".to_string(),
+        literal_union("Dungeon", &dungeons()),
+        literal_union("DungeonItemMode", &names(&variants(DungeonItemMode::OwnDungeon, next_dungeon_item_mode))),
+        literal_union("Prize", &prizes()),
+        interface("PoolEntry", &PoolEntry { name: String::new(), count: 0, progression: false, dungeon: None }, required, &[("name", "ItemId"), ("dungeon", "Dungeon | null")]),
+        tagged_union("PoolEdit", "type", &samples(&variants(PoolEdit::Add { items: Vec::new(), progression: false }, next_pool_edit)), defaulted::<PoolEdit>, &[("items", "ItemId[]"), ("item", "ItemId"), ("with", "ItemId")]),
+        tagged_union("AlbwError", "kind", &samples(&variants(Error::UnknownItem { name: String::new() }, next_error)), required, &[]),
+        interface("PlacedItem", &PlacedItem { check: String::new(), item: FillerItem::Bow01 }, required, &[("check", "CheckName"), ("item", "ItemId")]),
+        interface("Placement", &Placement { seed: 0, checks: Vec::new() }, required, &[("checks", "PlacedItem[]")]),
+        interface("Goal", &Goal::default(), defaulted::<Goal>, &[]),
+        interface("Options", &Options::default(), defaulted::<Options>, &[("plando", "PlacedItem[]"), ("exclusions", "CheckName[]"), ("pool_edits", "PoolEdit[]"), ("starting_items", "ItemId[]"), ("dungeon_items", "DungeonItemMode"), ("goal", "Goal")]),
+        interface("Playthrough", &Playthrough { beatable: false, spheres: Vec::new() }, required, &[("spheres", "PlacedItem[][]")]),
+        tagged_union("Violation", "kind", &samples(&variants(Violation::UnknownCheck { check: String::new() }, next_violation)), required, &[("check", "CheckName"), ("item", "ItemId"), ("expected", "ItemId"), ("found", "ItemId")]),
+        interface("Verification", &Verification { valid: false, violations: Vec::new() }, required, &[("violations", "Violation[]")]),
+        interface("KeyItemDepth", &KeyItemDepth { item: String::new(), check: String::new(), sphere: 0, checks_before: 0 }, required, &[("item", "ItemId"), ("check", "CheckName")]),
+        interface("Difficulty", &Difficulty {
+            spheres: 0,
+            key_items: Vec::new(),
+            average_checks_before_key_items: 0.0,
+            dungeon_progression_items: 0,
+            overworld_progression_items: 0,
+            score: 0.0,
+        }, required, &[("key_items", "KeyItemDepth[]")]),
+        interface("RequiredItem", &RequiredItem { item: String::new(), check: String::new() }, required, &[("item", "ItemId"), ("check", "CheckName")]),
+        "export type RegionMap = Record<string, CheckName[]>;\n".to_string(),
+        interface("RegionReport", &RegionReport { region: String::new(), barren: false, required_items: Vec::new() }, required, &[("required_items", "RequiredItem[]")]),
+        literal_union("HintKind", &names(&variants(HintKind::Path, next_hint_kind))),
+        interface("HintDistribution", &HintDistribution::default(), defaulted::<HintDistribution>, &[]),
+        interface("Hint", &Hint { ghost: String::new(), kind: HintKind::Path, text: String::new(), region: None, check: None, item: None }, required, &[
+            ("kind", "HintKind"),
+            ("region", "string | null"),
+            ("check", "CheckName | null"),
+            ("item", "ItemId | null"),
+        ]),
+        tagged_union("HintFact", "type", &samples(&variants(HintFact::ItemAtCheck { item: FillerItem::Bow01, check: String::new() }, next_hint_fact)), defaulted::<HintFact>, &[("item", "ItemId"), ("check", "CheckName")]),
+        interface("TrackerState", &TrackerState::default(), defaulted::<TrackerState>, &[("items", "ItemId[]"), ("checked", "CheckName[]"), ("hints", "HintFact[]"), ("small_keys", "Partial<Record<Dungeon, number>>"), ("prizes", "Partial<Record<Dungeon, Prize>>")]),
+        literal_union("Relevance", &names(&variants(Relevance::Unknown, next_relevance))),
+        interface("AvailableCheck", &AvailableCheck { check: String::new(), relevance: Relevance::Unknown, junk_only: false }, required, &[("check", "CheckName"), ("relevance", "Relevance")]),
+        interface("DungeonKeys", &DungeonKeys { dungeon: Dungeon::Eastern, keys_found: 0, keys_total: 0, keys_needed: 0, reachable_checks: 0, soft_lock_risk: false }, required, &[("dungeon", "Dungeon")]),
+        interface("DungeonSummary", &DungeonSummary {
+            dungeon: Dungeon::Eastern,
+            total_checks: 0,
+            checked: 0,
+            reachable: 0,
+            keys_found: 0,
+            keys_needed: 0,
+            big_key: None,
+            compass: None,
+            prize: None,
+        }, required, &[("dungeon", "Dungeon"), ("big_key", "boolean | null"), ("compass", "boolean | null"), ("prize", "ItemId | null")]),
+        interface("GoalStatus", &GoalStatus {
+            sages: 0,
+            lorule_castle_sages: 0,
+            yuganon_sages: 0,
+            lorule_castle_open: false,
+            triforce_reachable: false,
+        }, required, &[]),
+        interface("SeedRange", &SeedRange { start: 0, end: 0 }, defaulted::<SeedRange>, &[]),
+        interface("SeedFailure", &SeedFailure { seed: 0, message: String::new() }, required, &[]),
+        interface("SeedAnalysis", &SeedAnalysis::default(), required, &[
+            ("item_locations", "Record<ItemId, Record<CheckName, number>>"),
+            ("check_items", "Record<CheckName, Record<ItemId, number>>"),
+            ("sphere_counts", "Record<number, number>"),
+            ("failures", "SeedFailure[]"),
+        ]),
+        tagged_union("WorkerRequest", "type", &request_samples(), defaulted::<Request>, WORKER_FIELDS),
+        tagged_union("WorkerResponse", "type", &response_samples(), required, WORKER_FIELDS),
+    ];
+    format!("\n{}", sections.join("\n"))
+}
+
+// output of declarations(), kept in sync by tests/typescript.rs (UPDATE_TYPESCRIPT=1 rewrites it)
+#[wasm_bindgen(typescript_custom_section)]
+const TYPESCRIPT_DECLARATIONS: &str = r#"
+export type LogicMode =
+    | "Normal"
+    | "Hard"
+    | "GlitchBasic"
+    | "GlitchAdvanced"
+    | "GlitchHell"
+    | "NoLogic";
+
+export interface Logic {
+    lampless: boolean;
+    mode: LogicMode;
+    skip_trials: boolean;
+    super_items: boolean;
+    swordless_mode: boolean;
+    [option: string]: unknown;
+}
+
+export interface Settings {
+    logic: Logic;
+    [section: string]: unknown;
+}
+
+export type ItemId =
+    | "AccessHyruleBlacksmith"
+    | "AccessLoruleCastleField"
+    | "AccessMilkBar"
+    | "AccessPotionShop"
+    | "BeeBadge"
+    | "Bell"
+    | "BigBombFlower"
+    | "Bombs01"
+    | "Bombs02"
+    | "Boomerang01"
+    | "Boomerang02"
+    | "Bottle01"
+    | "Bottle02"
+    | "Bottle03"
+    | "Bottle04"
+    | "Bottle05"
+    | "Bow01"
+    | "Bow02"
+    | "BowOfLight"
+    | "DarkCompass"
+    | "DarkKeyBig"
+    | "DarkKeySmall01"
+    | "DarkKeySmall02"
+    | "DarkKeySmall03"
+    | "DarkKeySmall04"
+    | "DesertCompass"
+    | "DesertKeyBig"
+    | "DesertKeySmall01"
+    | "DesertKeySmall02"
+    | "DesertKeySmall03"
+    | "DesertKeySmall04"
+    | "DesertKeySmall05"
+    | "EasternCompass"
+    | "EasternKeyBig"
+    | "EasternKeySmall01"
+    | "EasternKeySmall02"
+    | "FireRod01"
+    | "FireRod02"
+    | "Flippers"
+    | "FoulFruit"
+    | "GalesCompass"
+    | "GalesKeyBig"
+    | "GalesKeySmall01"
+    | "GalesKeySmall02"
+    | "GalesKeySmall03"
+    | "GalesKeySmall04"
+    | "Glove01"
+    | "Glove02"
+    | "GoldBee"
+    | "Hammer01"
+    | "Hammer02"
+    | "HeartContainer01"
+    | "HeartContainer02"
+    | "HeartContainer03"
+    | "HeartContainer04"
+    | "HeartContainer05"
+    | "HeartContainer06"
+    | "HeartContainer07"
+    | "HeartContainer08"
+    | "HeartContainer09"
+    | "HeartContainer10"
+    | "HeartPiece01"
+    | "HeartPiece02"
+    | "HeartPiece03"
+    | "HeartPiece04"
+    | "HeartPiece05"
+    | "HeartPiece06"
+    | "HeartPiece07"
+    | "HeartPiece08"
+    | "HeartPiece09"
+    | "HeartPiece10"
+    | "HeartPiece11"
+    | "HeartPiece12"
+    | "HeartPiece13"
+    | "HeartPiece14"
+    | "HeartPiece15"
+    | "HeartPiece16"
+    | "HeartPiece17"
+    | "HeartPiece18"
+    | "HeartPiece19"
+    | "HeartPiece20"
+    | "HeartPiece21"
+    | "HeartPiece22"
+    | "HeartPiece23"
+    | "HeartPiece24"
+    | "HeartPiece25"
+    | "HeartPiece26"
+    | "HeartPiece27"
+    | "HeraCompass"
+    | "HeraKeyBig"
+    | "HeraKeySmall01"
+    | "HeraKeySmall02"
+    | "HintGlasses"
+    | "Hookshot01"
+    | "Hookshot02"
+    | "HylianShield"
+    | "HyruleSanctuaryKey"
+    | "IceCompass"
+    | "IceKeyBig"
+    | "IceKeySmall01"
+    | "IceKeySmall02"
+    | "IceKeySmall03"
+    | "IceRod01"
+    | "IceRod02"
+    | "Lamp01"
+    | "Lamp02"
+    | "LetterInABottle"
+    | "LoruleCastleCompass"
+    | "LoruleCastleKeySmall01"
+    | "LoruleCastleKeySmall02"
+    | "LoruleCastleKeySmall03"
+    | "LoruleCastleKeySmall04"
+    | "LoruleCastleKeySmall05"
+    | "LoruleSanctuaryKey"
+    | "Mail01"
+    | "Mail02"
+    | "MonsterGuts"
+    | "MonsterHorn"
+    | "MonsterTail"
+    | "Net01"
+    | "Net02"
+    | "OpenSanctuaryDoors"
+    | "OreBlue"
+    | "OreGreen"
+    | "OreRed"
+    | "OreYellow"
+    | "PegasusBoots"
+    | "PendantOfCourage"
+    | "PendantOfPower"
+    | "PendantOfWisdom"
+    | "Pouch"
+    | "PremiumMilk"
+    | "RaviosBracelet01"
+    | "RaviosBracelet02"
+    | "RupeeBlue"
+    | "RupeeGold01"
+    | "RupeeGold02"
+    | "RupeeGold03"
+    | "RupeeGold04"
+    | "RupeeGold05"
+    | "RupeeGold06"
+    | "RupeeGold07"
+    | "RupeeGold08"
+    | "RupeeGreen"
+    | "RupeePurple01"
+    | "RupeePurple02"
+    | "RupeePurple03"
+    | "RupeePurple04"
+    | "RupeePurple05"
+    | "RupeePurple06"
+    | "RupeePurple07"
+    | "RupeePurple08"
+    | "RupeePurple09"
+    | "RupeePurple10"
+    | "RupeePurple11"
+    | "RupeePurple12"
+    | "RupeePurple13"
+    | "RupeePurple14"
+    | "RupeePurple15"
+    | "RupeePurple16"
+    | "RupeePurple17"
+    | "RupeePurple18"
+    | "RupeeRed"
+    | "RupeeSilver01"
+    | "RupeeSilver02"
+    | "RupeeSilver03"
+    | "RupeeSilver04"
+    | "RupeeSilver05"
+    | "RupeeSilver06"
+    | "RupeeSilver07"
+    | "RupeeSilver08"
+    | "RupeeSilver09"
+    | "RupeeSilver10"
+    | "RupeeSilver11"
+    | "RupeeSilver12"
+    | "RupeeSilver13"
+    | "RupeeSilver14"
+    | "RupeeSilver15"
+    | "RupeeSilver16"
+    | "RupeeSilver17"
+    | "RupeeSilver18"
+    | "RupeeSilver19"
+    | "RupeeSilver20"
+    | "RupeeSilver21"
+    | "RupeeSilver22"
+    | "RupeeSilver23"
+    | "RupeeSilver24"
+    | "RupeeSilver25"
+    | "RupeeSilver26"
+    | "RupeeSilver27"
+    | "RupeeSilver28"
+    | "RupeeSilver29"
+    | "RupeeSilver30"
+    | "RupeeSilver31"
+    | "RupeeSilver32"
+    | "RupeeSilver33"
+    | "RupeeSilver34"
+    | "RupeeSilver35"
+    | "RupeeSilver36"
+    | "RupeeSilver37"
+    | "RupeeSilver38"
+    | "SageGulley"
+    | "SageImpa"
+    | "SageIrene"
+    | "SageOren"
+    | "SageOsfala"
+    | "SageRosso"
+    | "SageSeres"
+    | "SandRod01"
+    | "SandRod02"
+    | "ScootFruit"
+    | "Shield"
+    | "SkullCompass"
+    | "SkullEyeLeft"
+    | "SkullEyeRight"
+    | "SkullKeyBig"
+    | "SkullKeySmall01"
+    | "SkullKeySmall02"
+    | "SkullKeySmall03"
+    | "SmoothGem"
+    | "StaminaScroll"
+    | "StylishWomansHouseOpen"
+    | "SwampCompass"
+    | "SwampKeyBig"
+    | "SwampKeySmall01"
+    | "SwampKeySmall02"
+    | "SwampKeySmall03"
+    | "SwampKeySmall04"
+    | "Sword01"
+    | "Sword02"
+    | "Sword03"
+    | "Sword04"
+    | "ThievesCompass"
+    | "ThievesKeyBig"
+    | "ThievesKeySmall"
+    | "TornadoRod01"
+    | "TornadoRod02"
+    | "Triforce"
+    | "TurtleCompass"
+    | "TurtleKeyBig"
+    | "TurtleKeySmall01"
+    | "TurtleKeySmall02"
+    | "TurtleKeySmall03";
+
+export type CheckName = string;
+
+export type Dungeon =
+    | "Eastern"
+    | "Gales"
+    | "Hera"
+    | "Dark"
+    | "Swamp"
+    | "Skull"
+    | "Thieves"
+    | "Ice"
+    | "Desert"
+    | "Turtle"
+    | "LoruleCastle";
+
+export type DungeonItemMode =
+    | "own_dungeon"
+    | "any_dungeon"
+    | "overworld"
+    | "anywhere"
+    | "removed";
+
+export type Prize =
+    | "PendantOfCourage"
+    | "PendantOfWisdom"
+    | "PendantOfPower"
+    | "SageGulley"
+    | "SageOren"
+    | "SageSeres"
+    | "SageOsfala"
+    | "SageRosso"
+    | "SageIrene"
+    | "SageImpa";
+
+export interface PoolEntry {
+    count: number;
+    dungeon: Dungeon | null;
+    name: ItemId;
+    progression: boolean;
+}
+
+export type PoolEdit =
+    | { items: ItemId[]; progression?: boolean; type: "add" }
+    | { items: ItemId[]; type: "remove" }
+    | { item: ItemId; type: "replace"; with: ItemId };
+
+export type AlbwError =
+    | { kind: "UnknownItem"; name: string }
+    | { kind: "UnknownCheck"; name: string }
+    | { kind: "InvalidSettings"; message: string }
+    | { kind: "InvalidOptions"; message: string }
+    | { kind: "InvalidArgument"; message: string }
+    | { kind: "PlacementFailed"; message: string };
+
+export interface PlacedItem {
+    check: CheckName;
+    item: ItemId;
+}
+
+export interface Placement {
+    checks: PlacedItem[];
+    seed: number;
+}
+
+export interface Goal {
+    lorule_castle_sages?: number;
+    yuganon_sages?: number;
+}
+
+export interface Options {
+    dungeon_items?: DungeonItemMode;
+    exclusions?: CheckName[];
+    goal?: Goal;
+    plando?: PlacedItem[];
+    pool_edits?: PoolEdit[];
+    starting_items?: ItemId[];
+}
+
+export interface Playthrough {
+    beatable: boolean;
+    spheres: PlacedItem[][];
+}
+
+export type Violation =
+    | { check: CheckName; kind: "unknown_check" }
+    | { check: CheckName; kind: "duplicate_check" }
+    | { check: CheckName; kind: "empty_check" }
+    | { check: CheckName; expected: ItemId; found: ItemId; kind: "changed_fixed_check" }
+    | { count: number; item: ItemId; kind: "missing_item" }
+    | { count: number; item: ItemId; kind: "extra_item" }
+    | { check: CheckName; item: ItemId; kind: "misplaced_dungeon_item" }
+    | { kind: "unbeatable" };
+
+export interface Verification {
+    valid: boolean;
+    violations: Violation[];
+}
+
+export interface KeyItemDepth {
+    check: CheckName;
+    checks_before: number;
+    item: ItemId;
+    sphere: number;
+}
+
+export interface Difficulty {
+    average_checks_before_key_items: number;
+    dungeon_progression_items: number;
+    key_items: KeyItemDepth[];
+    overworld_progression_items: number;
+    score: number;
+    spheres: number;
+}
+
+export interface RequiredItem {
+    check: CheckName;
+    item: ItemId;
+}
+
+export type RegionMap = Record<string, CheckName[]>;
+
+export interface RegionReport {
+    barren: boolean;
+    region: string;
+    required_items: RequiredItem[];
+}
+
+export type HintKind =
+    | "path"
+    | "always"
+    | "sometimes"
+    | "barren";
+
+export interface HintDistribution {
+    barren?: number;
+    path?: number;
+    sometimes?: number;
+}
+
+export interface Hint {
+    check: CheckName | null;
+    ghost: string;
+    item: ItemId | null;
+    kind: HintKind;
+    region: string | null;
+    text: string;
+}
+
+export type HintFact =
+    | { check: CheckName; item: ItemId; type: "item_at_check" }
+    | { item: ItemId; region: string; type: "item_in_region" }
+    | { region: string; type: "path" }
+    | { region: string; type: "barren" };
+
+export interface TrackerState {
+    checked?: CheckName[];
+    hints?: HintFact[];
+    items?: ItemId[];
+    prizes?: Partial<Record<Dungeon, Prize>>;
+    small_keys?: Partial<Record<Dungeon, number>>;
+}
+
+export type Relevance =
+    | "unknown"
+    | "relevant"
+    | "irrelevant";
+
+export interface AvailableCheck {
+    check: CheckName;
+    junk_only: boolean;
+    relevance: Relevance;
+}
+
+export interface DungeonKeys {
+    dungeon: Dungeon;
+    keys_found: number;
+    keys_needed: number;
+    keys_total: number;
+    reachable_checks: number;
+    soft_lock_risk: boolean;
+}
+
+export interface DungeonSummary {
+    big_key: boolean | null;
+    checked: number;
+    compass: boolean | null;
+    dungeon: Dungeon;
+    keys_found: number;
+    keys_needed: number;
+    prize: ItemId | null;
+    reachable: number;
+    total_checks: number;
+}
+
+export interface GoalStatus {
+    lorule_castle_open: boolean;
+    lorule_castle_sages: number;
+    sages: number;
+    triforce_reachable: boolean;
+    yuganon_sages: number;
+}
+
+export interface SeedRange {
+    end: number;
+    start: number;
+}
+
+export interface SeedFailure {
+    message: string;
+    seed: number;
+}
+
+export interface SeedAnalysis {
+    check_items: Record<CheckName, Record<ItemId, number>>;
+    failures: SeedFailure[];
+    generated: number;
+    item_locations: Record<ItemId, Record<CheckName, number>>;
+    seeds: number;
+    sphere_counts: Record<number, number>;
+}
+
+export type WorkerRequest =
+    | { id: number; options?: Options; seed: number; settings: Settings; type: "init" }
+    | { id: number; type: "progression_items" }
+    | { id: number; type: "trash_items" }
+    | { id: number; type: "item_pool" }
+    | { id: number; items: ItemId[]; type: "available_checks" }
+    | { id: number; type: "generate_assumed_fill" }
+    | { id: number; seeds: SeedRange; type: "analyze_seeds" }
+    | { id: number; state: TrackerState; type: "annotated_checks" }
+    | { id: number; state: TrackerState; type: "dungeon_keys" }
+    | { id: number; state: TrackerState; type: "dungeon_summaries" }
+    | { id: number; state: TrackerState; type: "required_dungeons" }
+    | { id: number; state: TrackerState; type: "goal_status" };
+
+export type WorkerResponse =
+    | { id: number; seed: number; type: "ready" }
+    | { id: number; items: ItemId[]; type: "progression_items" }
+    | { id: number; items: ItemId[]; type: "trash_items" }
+    | { id: number; pool: PoolEntry[]; type: "item_pool" }
+    | { checks: CheckName[]; id: number; type: "available_checks" }
+    | { id: number; placement: Placement; type: "placement" }
+    | { analysis: SeedAnalysis; id: number; type: "analysis" }
+    | { annotated_checks: AvailableCheck[]; id: number; type: "annotated_checks" }
+    | { id: number; keys: DungeonKeys[]; type: "dungeon_keys" }
+    | { id: number; summaries: DungeonSummary[]; type: "dungeon_summaries" }
+    | { dungeons: Dungeon[]; id: number; type: "required_dungeons" }
+    | { id: number; status: GoalStatus; type: "goal_status" }
+    | { error: AlbwError; id: number; type: "error" };
+"#;
@@ -0,0 +1,105 @@
+use std::collections::{BTreeMap, HashSet};
+
+use randomizer::{filler::prefill_check_map, world::build_world_graph, Settings};
+use serde::Serialize;
+
+use crate::{
+    items::filler_item_to_string,
+    options::Options,
+    placement::{Placement, PADDING_ITEM},
+    pool::compute_placed_pools,
+    Error,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Violation {
+    UnknownCheck { check: String },
+    DuplicateCheck { check: String },
+    EmptyCheck { check: String },
+    // checks the world graph fills itself (events like opening the sanctuary) can't be changed
+    ChangedFixedCheck { check: String, expected: String, found: String },
+    MissingItem { item: String, count: usize },
+    ExtraItem { item: String, count: usize },
+    // a small key, big key or compass outside where the dungeon item mode allows
+    MisplacedDungeonItem { check: String, item: String },
+    Unbeatable,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Verification {
+    pub valid: bool,
+    pub violations: Vec<Violation>,
+}
+
+// checks a hand-edited placement against the item pool of its seed, the world graph and the logic
+pub fn verify_placement(settings: &Settings, placement: &Placement) -> Verification {
+    verify_placement_with(settings, &Options::default(), placement).expect("default options are valid")
+}
+
+// same as verify_placement, against the item pool the options make out of the seed's
+pub fn verify_placement_with(settings: &Settings, options: &Options, placement: &Placement) -> Result<Verification, Error> {
+    options.validate()?;
+    let mut violations = Vec::new();
+
+    let mut world_graph = build_world_graph();
+    let fixed = prefill_check_map(&mut world_graph);
+
+    // item name -> placed copies minus pool copies
+    let mut item_balance: BTreeMap<String, i64> = BTreeMap::new();
+    let pools = compute_placed_pools(settings, options, placement.seed)?;
+    for item in pools.progression.iter().chain(pools.trash.iter()) {
+        *item_balance.entry(filler_item_to_string(*item)).or_default() -= 1;
+    }
+    if options.edits_pool() {
+        let open = fixed.values().filter(|item| item.is_none()).count() as i64;
+        let padding = open - (pools.progression.len() + pools.trash.len()) as i64;
+        if padding > 0 {
+            *item_balance.entry(filler_item_to_string(PADDING_ITEM)).or_default() -= padding;
+        }
+    }
+
+    let mut seen: HashSet<&str> = HashSet::new();
+    for placed in &placement.checks {
+        if !seen.insert(placed.check.as_str()) {
+            violations.push(Violation::DuplicateCheck { check: placed.check.clone() });
+            continue;
+        }
+        match fixed.get(placed.check.as_str()) {
+            None => violations.push(Violation::UnknownCheck { check: placed.check.clone() }),
+            Some(Some(expected)) if *expected != placed.item => violations.push(Violation::ChangedFixedCheck {
+                check: placed.check.clone(),
+                expected: filler_item_to_string(*expected),
+                found: filler_item_to_string(placed.item),
+            }),
+            Some(Some(_)) => {}
+            Some(None) => {
+                if !options.allows(placed.item, &placed.check) {
+                    violations.push(Violation::MisplacedDungeonItem { check: placed.check.clone(), item: filler_item_to_string(placed.item) });
+                }
+                *item_balance.entry(filler_item_to_string(placed.item)).or_default() += 1;
+            }
+        }
+    }
+
+    let mut empty: Vec<&str> = fixed.iter()
+        .filter(|(check, item)| item.is_none() && !seen.contains(**check))
+        .map(|(check, _)| *check)
+        .collect();
+    empty.sort();
+    violations.extend(empty.into_iter().map(|check| Violation::EmptyCheck { check: check.to_string() }));
+
+    for (item, balance) in item_balance {
+        if balance < 0 {
+            violations.push(Violation::MissingItem { item, count: (-balance) as usize });
+        } else if balance > 0 {
+            violations.push(Violation::ExtraItem { item, count: balance as usize });
+        }
+    }
+
+    if !options.goal.is_beatable(settings, &placement.check_map(), &options.granted_items()) {
+        violations.push(Violation::Unbeatable);
+    }
+
+    Ok(Verification { valid: violations.is_empty(), violations })
+}
@@ -0,0 +1,300 @@
+use randomizer::{filler_item::FillerItem, settings::LogicMode, Seed, Settings};
+use serde::Serialize;
+use wasm_bindgen::{prelude::*, JsCast};
+
+use crate::{
+    analysis::{self, SeedRange},
+    difficulty::score_difficulty,
+    hints::{generate_hints, HintDistribution},
+    items::string_to_filler_item,
+    keys::find_dungeon_keys,
+    options::Options,
+    placement::{generate_assumed_fill_with, Placement},
+    playthrough::compute_playthrough,
+    pool::{pool_to_names, PoolEdit},
+    regions::{default_regions, find_barren_regions, RegionMap},
+    required::find_required_items,
+    summary::summarize_dungeons,
+    tracker::{Tracker, TrackerState},
+    verify,
+    typescript::{AvailableCheckArray, CheckNameArray, DungeonArray, DungeonKeysArray, DungeonSummaryArray, JsGoalStatus, HintArray, ItemIdArray, JsDifficulty, JsHintDistribution, JsOptions, JsPlacement, JsPlaythrough, JsRegionMap, JsSeedAnalysis, JsTrackerState, JsVerification, RegionReportArray, RequiredItemArray, JsSeedRange, JsSettings, PoolEditArray, PoolEntryArray, WorkerRequest, WorkerResponse},
+    worker::{Reply, Request, Response, Session},
+    Error,
+};
+
+#[wasm_bindgen]
+extern "C" {
+    // Use `js_namespace` here to bind `console.log(..)` instead of just
+    // `log(..)`
+    #[wasm_bindgen(js_namespace = console)]
+    fn log(s: &str);
+}
+
+// maps become plain objects instead of ES Maps, so results survive postMessage and JSON.stringify,
+// and None becomes null as the TypeScript declarations say
+fn to_js<T: Serialize>(value: &T) -> JsValue {
+    let serializer = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true).serialize_missing_as_null(true);
+    value.serialize(&serializer).unwrap()
+}
+
+fn from_js<T: serde::de::DeserializeOwned>(value: JsValue) -> Result<T, Error> {
+    serde_wasm_bindgen::from_value(value).map_err(|e| Error::InvalidArgument { message: e.to_string() })
+}
+
+fn settings_from_js(jsettings: JsSettings) -> Result<Settings, Error> {
+    serde_wasm_bindgen::from_value(jsettings.into()).map_err(|e| Error::InvalidSettings { message: e.to_string() })
+}
+
+#[wasm_bindgen]
+pub struct Cartridge {
+    tracker : Tracker,
+}
+
+#[wasm_bindgen]
+impl Cartridge {
+    #[wasm_bindgen(constructor)]
+    pub fn new(jsettings: JsSettings, seed: Seed, options_js: Option<JsOptions>) -> Result<Cartridge, JsValue> {
+        console_error_panic_hook::set_once();
+        let settings = settings_from_js(jsettings)?;
+        let options : Options = match options_js {
+            Some(options_js) => from_js(options_js.into())?,
+            None => Options::default(),
+        };
+
+        log("Generating cartridge...");
+        log(&format!("Seed:                           {}", seed));
+        //info!("Hash:                           {}", settings.hash().0);
+        log(&format!("Logic:                          {}", match settings.logic.mode {
+            LogicMode::Normal => "Normal",
+            LogicMode::Hard => "Hard",
+            LogicMode::GlitchBasic => "Glitched (Basic)",
+            LogicMode::GlitchAdvanced => "Glitched (Advanced)",
+            LogicMode::GlitchHell => "Glitched (Hell) - Did you really mean to choose this?",
+            LogicMode::NoLogic => "No Logic",
+        }));
+        log(&format!("Super Items:                    {}", if settings.logic.super_items {"Included"} else {"Not Included"}));
+        log(&format!("Trials:                         {}", if settings.logic.skip_trials {"Skipped"} else {"Normal"}));
+        log(&format!("Dark Rooms:                     {}", if settings.logic.lampless {"Lamp Not Required"} else {"Lamp Required"}));
+        log(&format!("Swords:                         {}", if settings.logic.swordless_mode {"Swordless Mode - NO SWORDS"} else {"Normal"}));
+        log(&format!("Sages:                          {} for Lorule Castle, {} for Yuganon", options.goal.lorule_castle_sages, options.goal.yuganon_sages));
+        log(&format!("Plando:                         {} pinned", options.plando.len()));
+        log(&format!("Starting Items:                 {}\n", if options.starting_items.is_empty() {"None".to_string()} else {pool_to_names(&options.starting_items).join(", ")}));
+
+        Ok(Cartridge {
+            tracker: Tracker::with_options(settings, options, seed)?,
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn get_trash_item_names(&self) -> ItemIdArray {
+        to_js(&self.tracker.pools().trash_names()).unchecked_into()
+    }
+
+    #[wasm_bindgen]
+    pub fn get_progression_item_names(&self) -> ItemIdArray {
+        to_js(&self.tracker.pools().progression_names()).unchecked_into()
+    }
+
+    #[wasm_bindgen]
+    pub fn get_item_pool(&self) -> PoolEntryArray {
+        let entries = self.tracker.pools().entries();
+        to_js(&entries).unchecked_into()
+    }
+
+    #[wasm_bindgen]
+    pub fn get_seed(&self) -> Seed {
+        self.tracker.seed()
+    }
+
+    #[wasm_bindgen]
+    pub fn get_available_checks(&self, obtained_items_js: ItemIdArray) -> Result<CheckNameArray, JsValue> {
+        let obtained_items_as_string : Vec<String> = from_js(obtained_items_js.into())?;
+        let obtained_items : Vec<FillerItem> = obtained_items_as_string.iter().map(string_to_filler_item).collect::<Result<_, _>>()?;
+
+        let reachable_check_names = self.tracker.reachable_checks(&obtained_items);
+        Ok(to_js(&reachable_check_names).unchecked_into())
+    }
+
+    // edits the item pool on top of the options it was created with, listings and
+    // generated placements use the edited pool from then on
+    #[wasm_bindgen]
+    pub fn edit_item_pool(&mut self, edits_js: PoolEditArray) -> Result<(), JsValue> {
+        let edits : Vec<PoolEdit> = from_js(edits_js.into())?;
+        Ok(self.tracker.edit_pool(edits)?)
+    }
+
+    // checks regions in hint facts refer to, they default to get_default_regions()
+    #[wasm_bindgen]
+    pub fn set_regions(&mut self, regions_js: JsRegionMap) -> Result<(), JsValue> {
+        let regions : RegionMap = from_js(regions_js.into())?;
+        Ok(self.tracker.set_regions(regions)?)
+    }
+
+    // available checks of a tracker state, flagged relevant or irrelevant by its recorded hints
+    #[wasm_bindgen]
+    pub fn get_annotated_checks(&self, state_js: JsTrackerState) -> Result<AvailableCheckArray, JsValue> {
+        let state : TrackerState = from_js(state_js.into())?;
+        Ok(to_js(&self.tracker.annotated_checks(&state)).unchecked_into())
+    }
+
+    // per dungeon checks opened by the small keys counted in the state, and whether spending them can soft-lock
+    #[wasm_bindgen]
+    pub fn get_dungeon_keys(&self, state_js: JsTrackerState) -> Result<DungeonKeysArray, JsValue> {
+        let state : TrackerState = from_js(state_js.into())?;
+        Ok(to_js(&find_dungeon_keys(&self.tracker, &state)).unchecked_into())
+    }
+
+    // dungeon panels: checks, keys, big key, compass and prize of every dungeon for the state
+    #[wasm_bindgen]
+    pub fn get_dungeon_summaries(&self, state_js: JsTrackerState) -> Result<DungeonSummaryArray, JsValue> {
+        let state : TrackerState = from_js(state_js.into())?;
+        Ok(to_js(&summarize_dungeons(&self.tracker, &state)).unchecked_into())
+    }
+
+    // whether Lorule Castle is open and the Triforce reachable for the state, with the sage counts of the options
+    #[wasm_bindgen]
+    pub fn get_goal_status(&self, state_js: JsTrackerState) -> Result<JsGoalStatus, JsValue> {
+        let state : TrackerState = from_js(state_js.into())?;
+        Ok(to_js(&self.tracker.goal_status(&state)).unchecked_into())
+    }
+
+    // dungeons whose prize is needed for the goal, using the prizes recorded in the state
+    #[wasm_bindgen]
+    pub fn get_required_dungeons(&self, state_js: JsTrackerState) -> Result<DungeonArray, JsValue> {
+        let state : TrackerState = from_js(state_js.into())?;
+        Ok(to_js(&self.tracker.required_dungeons(&state)).unchecked_into())
+    }
+
+    // this crate's assumed fill for the cartridge's seed, not the desktop randomizer's placement
+    #[wasm_bindgen]
+    pub fn generate_assumed_fill(&self) -> Result<JsPlacement, JsValue> {
+        let placement = generate_assumed_fill_with(self.tracker.settings(), self.tracker.options(), self.tracker.seed())?;
+        Ok(to_js(&placement).unchecked_into())
+    }
+
+    #[wasm_bindgen]
+    pub fn get_difficulty(&self, placement_js: JsPlacement) -> Result<JsDifficulty, JsValue> {
+        let placement : Placement = from_js(placement_js.into())?;
+        Ok(to_js(&score_difficulty(self.tracker.settings(), self.tracker.options(), &placement)?).unchecked_into())
+    }
+
+    // minimal sphere-by-sphere walk-through to Yuganon, for spoiler logs
+    #[wasm_bindgen]
+    pub fn get_playthrough(&self, placement_js: JsPlacement) -> Result<JsPlaythrough, JsValue> {
+        let placement : Placement = from_js(placement_js.into())?;
+        Ok(to_js(&compute_playthrough(self.tracker.settings(), self.tracker.options(), &placement)?).unchecked_into())
+    }
+
+    // Way of the Hero items of a generated or imported placement
+    #[wasm_bindgen]
+    pub fn get_required_items(&self, placement_js: JsPlacement) -> Result<RequiredItemArray, JsValue> {
+        let placement : Placement = from_js(placement_js.into())?;
+        Ok(to_js(&find_required_items(self.tracker.settings(), self.tracker.options(), &placement)?).unchecked_into())
+    }
+
+    // regions without any required item, `regions` defaults to one region per dungeon plus the overworld
+    #[wasm_bindgen]
+    pub fn get_barren_regions(&self, placement_js: JsPlacement, regions_js: Option<JsRegionMap>) -> Result<RegionReportArray, JsValue> {
+        let placement : Placement = from_js(placement_js.into())?;
+        let regions : RegionMap = match regions_js {
+            Some(regions_js) => from_js(regions_js.into())?,
+            None => default_regions(),
+        };
+        Ok(to_js(&find_barren_regions(self.tracker.settings(), self.tracker.options(), &placement, &regions)?).unchecked_into())
+    }
+
+    // hints for the ghosts of a placement, regions default to get_default_regions()
+    #[wasm_bindgen]
+    pub fn get_hints(&self, placement_js: JsPlacement, distribution_js: Option<JsHintDistribution>, regions_js: Option<JsRegionMap>) -> Result<HintArray, JsValue> {
+        let placement : Placement = from_js(placement_js.into())?;
+        let distribution : HintDistribution = match distribution_js {
+            Some(distribution_js) => from_js(distribution_js.into())?,
+            None => HintDistribution::default(),
+        };
+        let regions : RegionMap = match regions_js {
+            Some(regions_js) => from_js(regions_js.into())?,
+            None => default_regions(),
+        };
+        Ok(to_js(&generate_hints(self.tracker.settings(), self.tracker.options(), &placement, &regions, &distribution)?).unchecked_into())
+    }
+
+    #[wasm_bindgen]
+    pub fn get_default_regions(&self) -> JsRegionMap {
+        to_js(&default_regions()).unchecked_into()
+    }
+}
+
+// generates assumed fill placements for every seed in the range and aggregates them
+#[wasm_bindgen]
+pub fn analyze_seeds(jsettings: JsSettings, seed_range: JsSeedRange, options_js: Option<JsOptions>) -> Result<JsSeedAnalysis, JsValue> {
+    console_error_panic_hook::set_once();
+    let settings = settings_from_js(jsettings)?;
+    let seed_range: SeedRange = from_js(seed_range.into())?;
+    let options : Options = match options_js {
+        Some(options_js) => from_js(options_js.into())?,
+        None => Options::default(),
+    };
+
+    Ok(to_js(&analysis::analyze_seeds(&settings, &options, seed_range.into())).unchecked_into())
+}
+
+// checks a hand-edited placement: the pool of its seed placed exactly once, every check filled and beatable
+#[wasm_bindgen]
+pub fn verify_placement(jsettings: JsSettings, placement_js: JsPlacement, options_js: Option<JsOptions>) -> Result<JsVerification, JsValue> {
+    console_error_panic_hook::set_once();
+    let settings = settings_from_js(jsettings)?;
+    let placement : Placement = from_js(placement_js.into())?;
+    let options : Options = match options_js {
+        Some(options_js) => from_js(options_js.into())?,
+        None => Options::default(),
+    };
+
+    Ok(to_js(&verify::verify_placement_with(&settings, &options, &placement)?).unchecked_into())
+}
+
+// hosts a worker Session, the worker script only has to forward messages:
+//
+//     const host = new WorkerHost();
+//     onmessage = (event) => postMessage(host.handle(event.data));
+#[wasm_bindgen]
+pub struct WorkerHost {
+    session : Session,
+}
+
+#[wasm_bindgen]
+impl WorkerHost {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WorkerHost {
+        console_error_panic_hook::set_once();
+        WorkerHost {
+            session: Session::new(),
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn handle(&mut self, message: WorkerRequest) -> WorkerResponse {
+        let message: JsValue = message.into();
+        let response = match serde_wasm_bindgen::from_value::<Request>(message.clone()) {
+            Ok(request) => self.session.handle(request),
+            Err(e) => Response {
+                id: request_id(&message),
+                reply: Reply::Error { error: Error::InvalidArgument { message: e.to_string() } },
+            },
+        };
+        to_js(&response).unchecked_into()
+    }
+}
+
+impl Default for WorkerHost {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// id of a request that couldn't be parsed, so the page can still match the error to it
+fn request_id(message: &JsValue) -> u32 {
+    js_sys::Reflect::get(message, &JsValue::from_str("id"))
+        .ok()
+        .and_then(|id| id.as_f64())
+        .unwrap_or(0.0) as u32
+}
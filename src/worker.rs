@@ -0,0 +1,122 @@
+use randomizer::{Seed, Settings};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    analysis::{analyze_seeds, SeedAnalysis, SeedRange},
+    dungeons::Dungeon,
+    goal::GoalStatus,
+    items::string_to_filler_item,
+    keys::{find_dungeon_keys, DungeonKeys},
+    options::Options,
+    placement::{generate_assumed_fill_with, Placement},
+    pool::PoolEntry,
+    summary::{summarize_dungeons, DungeonSummary},
+    tracker::{AvailableCheck, Tracker, TrackerState},
+    Error,
+};
+
+// message-style API for hosting the module in a Web Worker: the page posts
+// requests with an id and matches the responses back to them by that id
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Command {
+    Init {
+        settings: Box<Settings>,
+        seed: Seed,
+        #[serde(default)]
+        options: Options,
+    },
+    ProgressionItems,
+    TrashItems,
+    ItemPool,
+    AvailableChecks { items: Vec<String> },
+    GenerateAssumedFill,
+    AnalyzeSeeds { seeds: SeedRange },
+    AnnotatedChecks { state: TrackerState },
+    DungeonKeys { state: TrackerState },
+    DungeonSummaries { state: TrackerState },
+    RequiredDungeons { state: TrackerState },
+    GoalStatus { state: TrackerState },
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Request {
+    pub id: u32,
+    #[serde(flatten)]
+    pub command: Command,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Reply {
+    Ready { seed: Seed },
+    ProgressionItems { items: Vec<String> },
+    TrashItems { items: Vec<String> },
+    ItemPool { pool: Vec<PoolEntry> },
+    AvailableChecks { checks: Vec<String> },
+    Placement { placement: Placement },
+    Analysis { analysis: SeedAnalysis },
+    AnnotatedChecks { annotated_checks: Vec<AvailableCheck> },
+    DungeonKeys { keys: Vec<DungeonKeys> },
+    DungeonSummaries { summaries: Vec<DungeonSummary> },
+    RequiredDungeons { dungeons: Vec<Dungeon> },
+    GoalStatus { status: GoalStatus },
+    Error { error: Error },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Response {
+    pub id: u32,
+    #[serde(flatten)]
+    pub reply: Reply,
+}
+
+// state of one worker, requests other than init need a tracker loaded first
+#[derive(Default)]
+pub struct Session {
+    tracker: Option<Tracker>,
+}
+
+impl Session {
+    pub fn new() -> Session {
+        Session::default()
+    }
+
+    pub fn handle(&mut self, request: Request) -> Response {
+        let reply = self.run(request.command).unwrap_or_else(|error| Reply::Error { error });
+        Response { id: request.id, reply }
+    }
+
+    fn run(&mut self, command: Command) -> Result<Reply, Error> {
+        if let Command::Init { settings, seed, options } = command {
+            self.tracker = Some(Tracker::with_options(*settings, options, seed)?);
+            return Ok(Reply::Ready { seed });
+        }
+
+        let tracker = self.tracker.as_ref().ok_or_else(|| Error::InvalidArgument {
+            message: "No cartridge loaded, send an init request first".to_string(),
+        })?;
+        Ok(match command {
+            Command::Init { .. } => unreachable!(),
+            Command::ProgressionItems => Reply::ProgressionItems { items: tracker.pools().progression_names() },
+            Command::TrashItems => Reply::TrashItems { items: tracker.pools().trash_names() },
+            Command::ItemPool => Reply::ItemPool { pool: tracker.pools().entries() },
+            Command::AvailableChecks { items } => {
+                let items = items.iter().map(string_to_filler_item).collect::<Result<_, _>>()?;
+                Reply::AvailableChecks { checks: tracker.reachable_checks(&items) }
+            }
+            Command::GenerateAssumedFill => Reply::Placement {
+                placement: generate_assumed_fill_with(tracker.settings(), tracker.options(), tracker.seed())?,
+            },
+            Command::AnalyzeSeeds { seeds } => Reply::Analysis {
+                analysis: analyze_seeds(tracker.settings(), tracker.options(), seeds.into()),
+            },
+            Command::AnnotatedChecks { state } => Reply::AnnotatedChecks { annotated_checks: tracker.annotated_checks(&state) },
+            Command::DungeonKeys { state } => Reply::DungeonKeys { keys: find_dungeon_keys(tracker, &state) },
+            Command::DungeonSummaries { state } => Reply::DungeonSummaries { summaries: summarize_dungeons(tracker, &state) },
+            Command::RequiredDungeons { state } => Reply::RequiredDungeons { dungeons: tracker.required_dungeons(&state) },
+            Command::GoalStatus { state } => Reply::GoalStatus { status: tracker.goal_status(&state) },
+        })
+    }
+}
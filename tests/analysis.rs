@@ -0,0 +1,31 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{analyze_seeds, generate_assumed_fill_with, DungeonItemMode, Options};
+
+mod common;
+use common::load_settings;
+
+#[test]
+fn analysis_counts_every_seed_once() {
+    let analysis = analyze_seeds(&load_settings("normal"), &Options::default(), 10..13);
+
+    assert_eq!(analysis.seeds, 3);
+    assert_eq!(analysis.generated as usize + analysis.failures.len(), 3);
+    assert_eq!(analysis.sphere_counts.values().sum::<u32>(), analysis.generated);
+    for items in analysis.check_items.values() {
+        assert!(items.values().sum::<u32>() <= analysis.generated);
+    }
+}
+
+#[test]
+fn analysis_uses_the_options() {
+    let settings = load_settings("normal");
+    let options = Options { dungeon_items: DungeonItemMode::Removed, ..Options::default() };
+    let analysis = analyze_seeds(&settings, &options, 10..11);
+
+    let placement = generate_assumed_fill_with(&settings, &options, 10).unwrap();
+    for placed in &placement.checks {
+        assert_eq!(analysis.check_items[&placed.check].values().sum::<u32>(), 1, "{}", placed.check);
+    }
+    assert!(!analysis.item_locations.keys().any(|item| item.contains("KeySmall")));
+}
@@ -0,0 +1,135 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::{
+    env, fs,
+    path::PathBuf,
+    process::{Command, Output},
+};
+
+use serde_json::Value;
+
+fn fixture(name: &str) -> String {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/settings").join(format!("{}.json", name)).display().to_string()
+}
+
+// file in the temp dir, named after the test so parallel tests don't collide
+fn temp_file(name: &str, contents: &str) -> String {
+    let path = env::temp_dir().join(format!("albw-track-{}-{}", std::process::id(), name));
+    fs::write(&path, contents).unwrap();
+    path.display().to_string()
+}
+
+fn albw_track(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_albw-track")).args(args).output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn json(output: &Output) -> Value {
+    serde_json::from_slice(&output.stdout).unwrap_or_else(|e| panic!("{}: {}", e, stdout(output)))
+}
+
+#[test]
+fn pools() {
+    let settings = fixture("normal");
+
+    let output = albw_track(&["pools", "--settings", &settings, "--json"]);
+    assert!(output.status.success());
+    let pools = json(&output);
+    assert!(!pools["progression"].as_array().unwrap().is_empty());
+    assert!(!pools["trash"].as_array().unwrap().is_empty());
+    assert!(!pools["pool"].as_array().unwrap().is_empty());
+
+    let output = albw_track(&["pools", "--settings", &settings]);
+    assert!(output.status.success());
+    let text = stdout(&output);
+    assert!(text.contains("Progression ("), "{}", text);
+    assert!(text.contains("Trash ("), "{}", text);
+}
+
+#[test]
+fn reachable() {
+    let settings = fixture("normal");
+    let inventory = temp_file("reachable-inventory.json", "[]");
+
+    let output = albw_track(&["reachable", "--settings", &settings, "--inventory", &inventory, "--json"]);
+    assert!(output.status.success());
+    let checks = json(&output);
+    let checks = checks.as_array().unwrap();
+    assert!(!checks.is_empty());
+
+    let output = albw_track(&["reachable", "--settings", &settings, "--inventory", &inventory]);
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with(&format!("{} reachable checks:", checks.len())));
+}
+
+#[test]
+fn generate() {
+    let settings = fixture("normal");
+
+    let output = albw_track(&["generate", "--settings", &settings, "--seed", "5", "--json"]);
+    assert!(output.status.success());
+    let placement = json(&output);
+    assert_eq!(placement["seed"], 5);
+    assert!(!placement["checks"].as_array().unwrap().is_empty());
+
+    let output = albw_track(&["generate", "--settings", &settings, "--seed", "5"]);
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with("Seed 5:"));
+}
+
+#[test]
+fn verify() {
+    let settings = fixture("normal");
+    let generated = albw_track(&["generate", "--settings", &settings, "--seed", "5", "--json"]);
+    let placement = temp_file("verify-placement.json", &stdout(&generated));
+
+    let output = albw_track(&["verify", "--settings", &settings, "--placement", &placement, "--json"]);
+    assert!(output.status.success());
+    assert_eq!(json(&output)["valid"], true);
+
+    let output = albw_track(&["verify", "--settings", &settings, "--placement", &placement]);
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("Placement for seed 5 is valid"));
+
+    let mut edited = json(&generated);
+    edited["checks"].as_array_mut().unwrap().pop();
+    let edited = temp_file("verify-edited.json", &edited.to_string());
+
+    let output = albw_track(&["verify", "--settings", &settings, "--placement", &edited, "--json"]);
+    assert!(!output.status.success());
+    assert_eq!(json(&output)["valid"], false);
+
+    let output = albw_track(&["verify", "--settings", &settings, "--placement", &edited]);
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("violations:"));
+}
+
+#[test]
+fn validate() {
+    let settings = fixture("normal");
+
+    let output = albw_track(&["validate", "--settings", &settings, "--json"]);
+    assert!(output.status.success());
+    let report = json(&output);
+    assert_eq!(report["valid"], true);
+    assert!(report["progression"].as_u64().unwrap() > 0);
+
+    let output = albw_track(&["validate", "--settings", &settings]);
+    assert!(output.status.success());
+    assert!(stdout(&output).contains(": valid ("));
+
+    let broken = temp_file("validate-broken.json", "{ \"logic\": ");
+
+    let output = albw_track(&["validate", "--settings", &broken, "--json"]);
+    assert!(!output.status.success());
+    let report = json(&output);
+    assert_eq!(report["valid"], false);
+    assert!(report["error"].as_str().unwrap().starts_with("invalid settings in"));
+
+    let output = albw_track(&["validate", "--settings", &broken]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr).unwrap().starts_with("error: invalid settings in"));
+}
@@ -0,0 +1,14 @@
+#![allow(dead_code)]
+
+use std::{fs, path::PathBuf};
+
+use albw_wasm::Settings;
+
+pub fn settings_json(name: &str) -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/settings").join(format!("{}.json", name));
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("Could not read {}: {}", path.display(), e))
+}
+
+pub fn load_settings(name: &str) -> Settings {
+    serde_json::from_str(&settings_json(name)).unwrap_or_else(|e| panic!("Could not parse settings {}: {}", name, e))
+}
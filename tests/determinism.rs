@@ -0,0 +1,70 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+// Pins pools and reachability against checked-in golden files so upstream
+// randomizer bumps that change logic show up as a test failure.
+
+use std::{fs, path::PathBuf};
+
+use albw_wasm::{compute_item_pools, find_reachable_check_names, pool_to_names, Seed, Settings};
+use serde::{Deserialize, Serialize};
+
+mod common;
+use common::load_settings;
+
+const SETTINGS: &[&str] = &["normal", "hard-swordless", "glitch-basic"];
+const SEEDS: &[Seed] = &[0, 1, 1337, 4294967295];
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Golden {
+    progression: Vec<String>,
+    trash: Vec<String>,
+    reachable_with_nothing: Vec<String>,
+    reachable_with_progression: Vec<String>,
+}
+
+fn compute(settings: &Settings, seed: Seed) -> Golden {
+    let pools = compute_item_pools(settings, seed);
+
+    let mut reachable_with_nothing = find_reachable_check_names(settings, &Vec::new());
+    reachable_with_nothing.sort();
+    let mut reachable_with_progression = find_reachable_check_names(settings, &pools.progression);
+    reachable_with_progression.sort();
+
+    Golden {
+        progression: pool_to_names(&pools.progression),
+        trash: pool_to_names(&pools.trash),
+        reachable_with_nothing,
+        reachable_with_progression,
+    }
+}
+
+#[test]
+fn pools_and_reachability_match_golden_files() {
+    let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+    let golden_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+
+    let mut mismatches = Vec::new();
+    for name in SETTINGS {
+        let settings = load_settings(name);
+        for seed in SEEDS {
+            let actual = compute(&settings, *seed);
+            let path = golden_dir.join(format!("{}-{}.json", name, seed));
+
+            if update {
+                // a snapshot of output that changes from run to run would only make the test flaky
+                assert_eq!(actual, compute(&settings, *seed), "{} is not deterministic for seed {}", name, seed);
+                fs::write(&path, serde_json::to_string_pretty(&actual).unwrap() + "\n").unwrap();
+                continue;
+            }
+
+            let json = fs::read_to_string(&path)
+                .unwrap_or_else(|_| panic!("Missing golden file {}, run with UPDATE_GOLDEN=1 to create it", path.display()));
+            let expected: Golden = serde_json::from_str(&json).unwrap();
+            if expected != actual {
+                mismatches.push(path.display().to_string());
+            }
+        }
+    }
+
+    assert!(mismatches.is_empty(), "Output differs from golden files (rerun with UPDATE_GOLDEN=1 and review the diff): {:?}", mismatches);
+}
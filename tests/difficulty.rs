@@ -0,0 +1,27 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{generate_assumed_fill, score_difficulty, Dungeon, FillerItem, Options};
+
+mod common;
+use common::load_settings;
+
+#[test]
+fn difficulty_counts_every_key_item_once() {
+    let settings = load_settings("normal");
+    let placement = generate_assumed_fill(&settings, 21).unwrap();
+    let difficulty = score_difficulty(&settings, &Options::default(), &placement).unwrap();
+
+    assert!(difficulty.spheres > 0);
+    assert_eq!(difficulty.dungeon_progression_items + difficulty.overworld_progression_items, difficulty.key_items.len());
+    assert!(difficulty.key_items.windows(2).all(|x| x[0].checks_before <= x[1].checks_before));
+    assert!(difficulty.score > 0.0);
+}
+
+#[test]
+fn dungeon_items_and_checks_are_classified() {
+    assert_eq!(Dungeon::of_item(FillerItem::GalesKeySmall03), Some(Dungeon::Gales));
+    assert_eq!(Dungeon::of_item(FillerItem::LoruleCastleCompass), Some(Dungeon::LoruleCastle));
+    assert_eq!(Dungeon::of_item(FillerItem::SkullEyeRight), None);
+    assert_eq!(Dungeon::of_check("[EP] (1F) Bow Chest"), Some(Dungeon::Eastern));
+    assert_eq!(Dungeon::of_check("Link's House"), None);
+}
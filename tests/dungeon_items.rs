@@ -0,0 +1,75 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{
+    dungeons::all_dungeon_items, generate_assumed_fill_with, verify_placement_with, Dungeon, DungeonItemMode, Options,
+    PlacedItem, Tracker, TrackerState, Violation,
+};
+
+mod common;
+use common::load_settings;
+
+#[test]
+fn own_dungeon_items_stay_home() {
+    let settings = load_settings("normal");
+    let options = Options { dungeon_items: DungeonItemMode::OwnDungeon, ..Options::default() };
+    let placement = generate_assumed_fill_with(&settings, &options, 7).unwrap();
+
+    for placed in &placement.checks {
+        if let Some(dungeon) = Dungeon::of_item(placed.item) {
+            assert_eq!(Dungeon::of_check(&placed.check), Some(dungeon), "{:?} at {}", placed.item, placed.check);
+        }
+    }
+    assert!(verify_placement_with(&settings, &options, &placement).unwrap().valid);
+}
+
+#[test]
+fn overworld_dungeon_items_leave_the_dungeons() {
+    let settings = load_settings("normal");
+    let options = Options { dungeon_items: DungeonItemMode::Overworld, ..Options::default() };
+    let placement = generate_assumed_fill_with(&settings, &options, 7).unwrap();
+
+    for placed in placement.checks.iter().filter(|x| Dungeon::of_item(x.item).is_some()) {
+        assert_eq!(Dungeon::of_check(&placed.check), None, "{:?} at {}", placed.item, placed.check);
+    }
+}
+
+#[test]
+fn verify_flags_misplaced_dungeon_items() {
+    let settings = load_settings("normal");
+    let options = Options { dungeon_items: DungeonItemMode::OwnDungeon, ..Options::default() };
+    let mut placement = generate_assumed_fill_with(&settings, &options, 7).unwrap();
+
+    let key = placement.checks.iter().position(|x| Dungeon::of_item(x.item).is_some()).unwrap();
+    // an overworld check the world graph doesn't fill itself
+    let open = |placed: &PlacedItem| Options { plando: vec![placed.clone()], ..Options::default() }.validate().is_ok();
+    let overworld = placement.checks.iter()
+        .position(|x| Dungeon::of_check(&x.check).is_none() && Dungeon::of_item(x.item).is_none() && open(x))
+        .unwrap();
+    let item = placement.checks[key].item;
+    placement.checks[key].item = placement.checks[overworld].item;
+    placement.checks[overworld].item = item;
+
+    let verification = verify_placement_with(&settings, &options, &placement).unwrap();
+    assert!(verification.violations.iter().any(|x| matches!(x, Violation::MisplacedDungeonItem { .. })));
+}
+
+#[test]
+fn removed_dungeon_items_are_always_owned() {
+    let plain = Tracker::new(load_settings("normal"), 7);
+    let options = Options { dungeon_items: DungeonItemMode::Removed, ..Options::default() };
+    let keysy = Tracker::with_options(load_settings("normal"), options, 7).unwrap();
+
+    assert!(keysy.pools().entries().iter().all(|entry| entry.dungeon.is_none()));
+    assert_eq!(keysy.available_checks(&TrackerState::default()), plain.reachable_checks(&all_dungeon_items()));
+}
+
+#[test]
+fn pool_entries_name_their_dungeon() {
+    let tracker = Tracker::new(load_settings("normal"), 7);
+    for entry in tracker.pools().entries() {
+        let expected = Dungeon::ALL.into_iter().find(|dungeon| entry.name.starts_with(dungeon.item_prefix()) && entry.name.contains("Key"));
+        if let Some(dungeon) = expected {
+            assert_eq!(entry.dungeon, Some(dungeon), "{}", entry.name);
+        }
+    }
+}
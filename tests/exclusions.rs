@@ -0,0 +1,49 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{compute_item_pools, generate_assumed_fill_with, Error, Options, Tracker, TrackerState};
+
+mod common;
+use common::load_settings;
+
+// open checks reachable from the start, so excluding them matters to the fill
+fn excludable(tracker: &Tracker, count: usize) -> Options {
+    let exclusions = tracker.available_checks(&TrackerState::default())
+        .into_iter()
+        .filter(|check| Options { exclusions: [check.clone()].into(), ..Options::default() }.validate().is_ok())
+        .take(count)
+        .collect();
+    Options { exclusions, ..Options::default() }
+}
+
+#[test]
+fn excluded_checks_only_get_trash() {
+    let settings = load_settings("normal");
+    let options = excludable(&Tracker::new(load_settings("normal"), 7), 5);
+    let progression = compute_item_pools(&settings, 7).progression;
+
+    let placement = generate_assumed_fill_with(&settings, &options, 7).unwrap();
+    for check in &options.exclusions {
+        let item = placement.item_at(check).unwrap();
+        assert!(!progression.contains(&item), "{} got {:?}", check, item);
+    }
+}
+
+#[test]
+fn exclusions_must_be_world_graph_checks() {
+    let settings = load_settings("normal");
+    let options = Options { exclusions: ["Nowhere".to_string()].into(), ..Options::default() };
+    assert!(matches!(generate_assumed_fill_with(&settings, &options, 7), Err(Error::UnknownCheck { .. })));
+    assert!(Tracker::with_options(settings, options, 7).is_err());
+}
+
+#[test]
+fn tracker_marks_excluded_checks_junk_only() {
+    let settings = load_settings("normal");
+    let options = excludable(&Tracker::new(load_settings("normal"), 7), 1);
+    let tracker = Tracker::with_options(settings, options.clone(), 7).unwrap();
+
+    for check in tracker.annotated_checks(&TrackerState::default()) {
+        assert_eq!(check.junk_only, options.exclusions.contains(&check.check), "{}", check.check);
+    }
+    assert!(generate_assumed_fill_with(tracker.settings(), tracker.options(), 7).is_ok());
+}
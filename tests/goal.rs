@@ -0,0 +1,51 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{compute_playthrough, find_required_items, generate_assumed_fill_with, Error, Goal, Options, Prize, Tracker, TrackerState};
+
+mod common;
+use common::load_settings;
+
+#[test]
+fn sage_counts_are_validated() {
+    let more_for_lorule_castle = Options { goal: Goal { lorule_castle_sages: 8, yuganon_sages: 7 }, ..Options::default() };
+    assert!(matches!(more_for_lorule_castle.validate(), Err(Error::InvalidOptions { .. })));
+    assert!(Options { goal: Goal { lorule_castle_sages: 0, yuganon_sages: 3 }, ..Options::default() }.validate().is_ok());
+    let too_many = Options { goal: Goal { lorule_castle_sages: 7, yuganon_sages: 9 }, ..Options::default() };
+    assert!(Tracker::with_options(load_settings("normal"), too_many, 7).is_err());
+}
+
+#[test]
+fn the_goal_is_reachable_with_everything() {
+    let options = Options { goal: Goal { lorule_castle_sages: 4, yuganon_sages: 5 }, ..Options::default() };
+    let tracker = Tracker::with_options(load_settings("normal"), options, 7).unwrap();
+    let everything = TrackerState { items: tracker.pools().progression.clone(), ..TrackerState::default() };
+    let status = tracker.goal_status(&everything);
+    assert_eq!((status.lorule_castle_sages, status.yuganon_sages), (4, 5));
+    assert!(status.lorule_castle_open);
+    assert!(status.triforce_reachable);
+
+    let nothing = tracker.goal_status(&TrackerState::default());
+    assert!(!nothing.triforce_reachable);
+}
+
+#[test]
+fn fewer_sages_open_lorule_castle_sooner() {
+    let all_sages = Tracker::new(load_settings("normal"), 7);
+    let options = Options { goal: Goal { lorule_castle_sages: 0, yuganon_sages: 0 }, ..Options::default() };
+    let no_sages = Tracker::with_options(load_settings("normal"), options, 7).unwrap();
+    let mut state = TrackerState { items: all_sages.pools().progression.clone(), ..TrackerState::default() };
+    state.items.retain(|item| Prize::of_item(*item).is_none_or(|prize| !prize.is_sage()));
+
+    assert!(no_sages.goal_status(&state).lorule_castle_open);
+    assert!(no_sages.available_checks(&state).len() >= all_sages.available_checks(&state).len());
+}
+
+#[test]
+fn placements_honor_the_goal() {
+    let settings = load_settings("normal");
+    let options = Options { goal: Goal { lorule_castle_sages: 3, yuganon_sages: 5 }, ..Options::default() };
+    let placement = generate_assumed_fill_with(&settings, &options, 7).unwrap();
+
+    assert!(compute_playthrough(&settings, &options, &placement).unwrap().beatable);
+    assert!(!find_required_items(&settings, &options, &placement).unwrap().is_empty());
+}
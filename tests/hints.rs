@@ -0,0 +1,77 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{
+    all_check_names, default_regions, generate_hints, generate_assumed_fill,
+    hints::{item_display_name, ALWAYS_HINT_CHECKS, HINT_GHOSTS},
+    FillerItem, HintDistribution, HintKind, Options,
+};
+use randomizer::{filler::prefill_check_map, world::build_world_graph};
+
+mod common;
+use common::load_settings;
+
+#[test]
+fn hints_are_deterministic_and_use_distinct_ghosts() {
+    let settings = load_settings("normal");
+    let placement = generate_assumed_fill(&settings, 8).unwrap();
+    let regions = default_regions();
+    let distribution = HintDistribution::default();
+
+    let hints = generate_hints(&settings, &Options::default(), &placement, &regions, &distribution).unwrap();
+    assert_eq!(hints, generate_hints(&settings, &Options::default(), &placement, &regions, &distribution).unwrap());
+
+    let mut ghosts: Vec<_> = hints.iter().map(|x| x.ghost.clone()).collect();
+    ghosts.dedup();
+    assert_eq!(ghosts.len(), hints.len());
+    assert!(hints.iter().filter(|x| x.kind == HintKind::Sometimes).count() <= distribution.sometimes);
+}
+
+#[test]
+fn item_hints_name_the_placed_item() {
+    let settings = load_settings("normal");
+    let placement = generate_assumed_fill(&settings, 8).unwrap();
+    let hints = generate_hints(&settings, &Options::default(), &placement, &default_regions(), &HintDistribution::default()).unwrap();
+
+    for hint in hints.iter().filter(|x| x.kind == HintKind::Sometimes) {
+        let item = placement.item_at(hint.check.as_ref().unwrap()).unwrap();
+        assert!(hint.text.contains(&item_display_name(item)));
+    }
+}
+
+#[test]
+fn display_names_drop_copy_numbers() {
+    assert_eq!(item_display_name(FillerItem::Bow01), "Bow");
+    assert_eq!(item_display_name(FillerItem::RaviosBracelet02), "Ravios Bracelet");
+}
+
+#[test]
+fn hint_ghosts_are_distinct() {
+    let mut ghosts = HINT_GHOSTS.to_vec();
+    ghosts.sort();
+    ghosts.dedup();
+    assert_eq!(ghosts.len(), HINT_GHOSTS.len());
+}
+
+#[test]
+fn always_hint_checks_exist() {
+    let checks = all_check_names();
+    for check in ALWAYS_HINT_CHECKS {
+        assert!(checks.iter().any(|x| x == check), "{} is not a check", check);
+    }
+}
+
+#[test]
+fn sometimes_hints_skip_fixed_checks() {
+    let settings = load_settings("normal");
+    let mut world_graph = build_world_graph();
+    let fixed_checks = prefill_check_map(&mut world_graph);
+    let distribution = HintDistribution { sometimes: 40, ..HintDistribution::default() };
+
+    for seed in 0..5 {
+        let placement = generate_assumed_fill(&settings, seed).unwrap();
+        let hints = generate_hints(&settings, &Options::default(), &placement, &default_regions(), &distribution).unwrap();
+        for hint in hints.iter().filter(|x| x.kind == HintKind::Sometimes) {
+            assert_eq!(fixed_checks[hint.check.as_deref().unwrap()], None);
+        }
+    }
+}
@@ -0,0 +1,66 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{
+    find_dungeon_keys, keys::key_inventory, Dungeon, DungeonItemMode, FillerItem, Options, Tracker, TrackerState,
+};
+
+mod common;
+use common::load_settings;
+
+#[test]
+fn key_counts_become_the_first_numbered_keys() {
+    let mut state = TrackerState { items: vec![FillerItem::DesertKeySmall05, FillerItem::Bow01], ..TrackerState::default() };
+    let inventory = key_inventory(&state);
+    assert!(inventory.contains(&FillerItem::DesertKeySmall01));
+    assert!(!inventory.contains(&FillerItem::DesertKeySmall05));
+    assert!(inventory.contains(&FillerItem::Bow01));
+
+    state.small_keys.insert(Dungeon::Desert, 2);
+    let inventory = key_inventory(&state);
+    assert!(inventory.contains(&FillerItem::DesertKeySmall02));
+    assert!(!inventory.contains(&FillerItem::DesertKeySmall03));
+}
+
+#[test]
+fn dungeon_keys_cover_every_dungeon() {
+    let tracker = Tracker::new(load_settings("normal"), 7);
+    let none = find_dungeon_keys(&tracker, &TrackerState::default());
+    assert_eq!(none.iter().map(|x| x.dungeon).collect::<Vec<_>>(), Dungeon::ALL.to_vec());
+    assert!(none.iter().all(|x| x.keys_found == 0 && !x.soft_lock_risk));
+
+    let desert = none.iter().find(|x| x.dungeon == Dungeon::Desert).unwrap();
+    assert_eq!(desert.keys_total, 5);
+
+    let mut state = TrackerState::default();
+    state.small_keys = Dungeon::ALL.into_iter().map(|dungeon| (dungeon, dungeon.small_keys().len())).collect();
+    for (with_keys, without) in find_dungeon_keys(&tracker, &state).iter().zip(&none) {
+        assert_eq!(with_keys.keys_found, with_keys.keys_total);
+        assert!(with_keys.reachable_checks >= without.reachable_checks, "{:?}", with_keys.dungeon);
+        assert!(!with_keys.soft_lock_risk);
+        assert!(with_keys.keys_needed <= with_keys.keys_total);
+    }
+}
+
+#[test]
+fn keys_short_of_the_last_door_risk_a_soft_lock() {
+    let tracker = Tracker::new(load_settings("normal"), 7);
+    let mut state = TrackerState { items: tracker.pools().progression.clone(), ..TrackerState::default() };
+    state.small_keys.insert(Dungeon::Desert, 1);
+
+    let keys = find_dungeon_keys(&tracker, &state);
+    let desert = keys.iter().find(|x| x.dungeon == Dungeon::Desert).unwrap();
+    assert_eq!(desert.keys_found, 1);
+    assert!(desert.keys_needed > 1);
+    assert!(desert.soft_lock_risk);
+    assert!(keys.iter().filter(|x| x.dungeon != Dungeon::Desert).all(|x| !x.soft_lock_risk));
+}
+
+#[test]
+fn removed_keys_are_all_found() {
+    let options = Options { dungeon_items: DungeonItemMode::Removed, ..Options::default() };
+    let tracker = Tracker::with_options(load_settings("normal"), options, 7).unwrap();
+    for keys in find_dungeon_keys(&tracker, &TrackerState::default()) {
+        assert_eq!(keys.keys_found, keys.keys_total);
+        assert!(!keys.soft_lock_risk);
+    }
+}
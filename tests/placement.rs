@@ -0,0 +1,25 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{compute_item_pools, generate_assumed_fill, pool_to_names, Goal};
+
+mod common;
+use common::load_settings;
+
+#[test]
+fn placement_is_deterministic_per_seed() {
+    let settings = load_settings("normal");
+    assert_eq!(generate_assumed_fill(&settings, 7).unwrap(), generate_assumed_fill(&settings, 7).unwrap());
+}
+
+#[test]
+fn placement_places_the_whole_pool_and_is_beatable() {
+    let settings = load_settings("normal");
+    let placement = generate_assumed_fill(&settings, 7).unwrap();
+    let pools = compute_item_pools(&settings, 7);
+
+    let placed: Vec<_> = placement.checks.iter().map(|x| x.item).collect();
+    for name in pool_to_names(&pools.progression).iter().chain(pool_to_names(&pools.trash).iter()) {
+        assert!(pool_to_names(&placed).contains(name), "{} was not placed", name);
+    }
+    assert!(Goal::default().is_beatable(&settings, &placement.check_map(), &Vec::new()));
+}
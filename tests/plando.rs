@@ -0,0 +1,86 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{
+    compute_item_pools, compute_item_pools_with, find_reachable_check_names, find_required_items, generate_assumed_fill,
+    generate_assumed_fill_with, string_to_filler_item, Error, FillerItem, Goal, Options, PlacedItem, Settings,
+};
+
+mod common;
+use common::load_settings;
+
+fn pin(check: &str, item: FillerItem) -> PlacedItem {
+    PlacedItem { check: check.to_string(), item }
+}
+
+// a check that takes the item somewhere the item itself is needed to reach
+fn locking_check(settings: &Settings, item: FillerItem) -> String {
+    let placement = generate_assumed_fill(settings, 7).unwrap();
+    let mut without_item = compute_item_pools(settings, 7).progression;
+    without_item.retain(|x| *x != item);
+    let reachable = find_reachable_check_names(settings, &without_item);
+
+    placement.checks.iter()
+        .map(|x| x.check.clone())
+        .filter(|check| !reachable.contains(check))
+        .find(|check| Options { plando: vec![pin(check, item)], ..Options::default() }.validate().is_ok())
+        .expect("some check needs the item")
+}
+
+#[test]
+fn pins_are_placed_and_taken_out_of_the_pools() {
+    let settings = load_settings("normal");
+    let unpinned = generate_assumed_fill(&settings, 7).unwrap();
+    let check = unpinned.checks.iter()
+        .find(|x| x.item != FillerItem::Bow01 && Options { plando: vec![pin(&x.check, FillerItem::Bow01)], ..Options::default() }.validate().is_ok())
+        .unwrap()
+        .check
+        .clone();
+    let options = Options { plando: vec![pin(&check, FillerItem::Bow01)], ..Options::default() };
+
+    let placement = generate_assumed_fill_with(&settings, &options, 7).unwrap();
+    assert_eq!(placement.item_at(&check), Some(FillerItem::Bow01));
+    assert_eq!(placement.checks.iter().filter(|x| x.item == FillerItem::Bow01).count(), 1);
+    assert!(Goal::default().is_beatable(&settings, &placement.check_map(), &Vec::new()));
+
+    let pools = compute_item_pools_with(&settings, &options, 7).unwrap();
+    assert!(!pools.progression.contains(&FillerItem::Bow01));
+    assert_eq!(pools.progression.len() + 1, compute_item_pools(&settings, 7).progression.len());
+}
+
+#[test]
+fn pins_must_name_distinct_open_checks() {
+    let settings = load_settings("normal");
+    let unknown = Options { plando: vec![pin("Nowhere", FillerItem::Bow01)], ..Options::default() };
+    assert!(matches!(generate_assumed_fill_with(&settings, &unknown, 7), Err(Error::UnknownCheck { .. })));
+
+    let check = locking_check(&settings, FillerItem::Bow01);
+    let twice = Options { plando: vec![pin(&check, FillerItem::Bow01), pin(&check, FillerItem::Lamp01)], ..Options::default() };
+    assert!(matches!(generate_assumed_fill_with(&settings, &twice, 7), Err(Error::InvalidOptions { .. })));
+}
+
+#[test]
+fn pinning_more_copies_than_the_pool_holds_fails() {
+    let settings = load_settings("normal");
+    let placement = generate_assumed_fill(&settings, 7).unwrap();
+    let open: Vec<_> = placement.checks.iter()
+        .map(|x| x.check.as_str())
+        .filter(|check| Options { plando: vec![pin(check, FillerItem::Bow01)], ..Options::default() }.validate().is_ok())
+        .take(2)
+        .collect();
+    let options = Options { plando: vec![pin(open[0], FillerItem::Bow01), pin(open[1], FillerItem::Bow01)], ..Options::default() };
+    assert!(matches!(compute_item_pools_with(&settings, &options, 7), Err(Error::InvalidOptions { .. })));
+}
+
+#[test]
+fn pins_that_lock_a_required_item_are_rejected() {
+    let settings = load_settings("normal");
+    let placement = generate_assumed_fill(&settings, 7).unwrap();
+    let required = find_required_items(&settings, &Options::default(), &placement).unwrap();
+    let item = string_to_filler_item(&required[0].item).unwrap();
+    let options = Options { plando: vec![pin(&locking_check(&settings, item), item)], ..Options::default() };
+
+    match generate_assumed_fill_with(&settings, &options, 7) {
+        Err(Error::PlacementFailed { message }) => assert!(message.contains("unbeatable")),
+        other => panic!("expected a placement failure, got {:?}", other.map(|x| x.seed)),
+    }
+}
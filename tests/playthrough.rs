@@ -0,0 +1,41 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{
+    compute_playthrough, find_required_items, generate_assumed_fill, generate_assumed_fill_with, DungeonItemMode, Goal, Options,
+    GOAL_ITEM,
+};
+
+mod common;
+use common::load_settings;
+
+#[test]
+fn playthrough_ends_with_the_goal_and_every_pickup_is_needed() {
+    let settings = load_settings("normal");
+    let placement = generate_assumed_fill(&settings, 12).unwrap();
+    let playthrough = compute_playthrough(&settings, &Options::default(), &placement).unwrap();
+    assert!(playthrough.beatable);
+    assert!(playthrough.spheres.last().unwrap().iter().any(|x| x.item == GOAL_ITEM));
+
+    // keeping only the playthrough's pickups is still beatable
+    let kept: Vec<_> = playthrough.spheres.iter().flatten().map(|x| x.check.clone()).collect();
+    let mut check_map = placement.check_map();
+    let progression = albw_wasm::compute_item_pools(&settings, 12).progression;
+    for (check, item) in check_map.iter_mut() {
+        if item.is_some_and(|x| progression.contains(&x)) && !kept.iter().any(|x| x == check) {
+            *item = None;
+        }
+    }
+    assert!(Goal::default().is_beatable(&settings, &check_map, &Vec::new()));
+}
+
+#[test]
+fn removed_dungeon_items_are_owned_in_the_playthrough() {
+    let settings = load_settings("normal");
+    let options = Options { dungeon_items: DungeonItemMode::Removed, ..Options::default() };
+    let placement = generate_assumed_fill_with(&settings, &options, 12).unwrap();
+
+    let playthrough = compute_playthrough(&settings, &options, &placement).unwrap();
+    assert!(playthrough.beatable);
+    assert!(playthrough.spheres.last().unwrap().iter().any(|x| x.item == GOAL_ITEM));
+    assert!(!find_required_items(&settings, &options, &placement).unwrap().is_empty());
+}
@@ -0,0 +1,98 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{
+    compute_item_pools, compute_item_pools_with, filler_item_to_string, generate_assumed_fill_with, pool_to_names, verify_placement_with, Error,
+    FillerItem, Options, PoolEdit, Tracker,
+};
+
+mod common;
+use common::load_settings;
+
+fn silver_rupees() -> Vec<FillerItem> {
+    let settings = load_settings("normal");
+    compute_item_pools(&settings, 7).trash.into_iter().filter(|item| filler_item_to_string(*item).starts_with("RupeeSilver")).collect()
+}
+
+#[test]
+fn edits_change_the_listed_pools() {
+    let settings = load_settings("normal");
+    let before = compute_item_pools(&settings, 7);
+    let replaced = *before.trash.iter().find(|item| !silver_rupees().contains(item)).unwrap();
+    let options = Options {
+        pool_edits: vec![
+            PoolEdit::Remove { items: silver_rupees() },
+            PoolEdit::Add { items: vec![FillerItem::HeartContainer01, FillerItem::HeartContainer01], progression: false },
+            PoolEdit::Replace { item: replaced, with: FillerItem::PegasusBoots },
+        ],
+        ..Options::default()
+    };
+
+    let after = compute_item_pools_with(&settings, &options, 7).unwrap();
+    assert!(!pool_to_names(&after.trash).iter().any(|name| name.starts_with("RupeeSilver")));
+    assert_eq!(after.progression, before.progression);
+    assert!(after.trash.contains(&FillerItem::PegasusBoots));
+    assert_eq!(after.trash.iter().filter(|x| **x == FillerItem::HeartContainer01).count(), before.trash.iter().filter(|x| **x == FillerItem::HeartContainer01).count() + 2);
+}
+
+#[test]
+fn placements_of_a_shrunk_pool_are_padded_and_verify() {
+    let settings = load_settings("normal");
+    let options = Options { pool_edits: vec![PoolEdit::Remove { items: silver_rupees() }], ..Options::default() };
+
+    let placement = generate_assumed_fill_with(&settings, &options, 7).unwrap();
+    assert!(!placement.checks.iter().any(|x| silver_rupees().contains(&x.item)));
+    let verification = verify_placement_with(&settings, &options, &placement).unwrap();
+    assert!(verification.valid, "{:?}", verification.violations);
+}
+
+#[test]
+fn placements_of_a_grown_pool_drop_rupees_and_verify() {
+    let settings = load_settings("normal");
+    let added = vec![FillerItem::HeartContainer01; 6];
+    let options = Options { pool_edits: vec![PoolEdit::Add { items: added, progression: false }], ..Options::default() };
+
+    let placement = generate_assumed_fill_with(&settings, &options, 7).unwrap();
+    let hearts = |items: &[FillerItem]| items.iter().filter(|x| **x == FillerItem::HeartContainer01).count();
+    let placed: Vec<FillerItem> = placement.checks.iter().map(|x| x.item).collect();
+    let before = compute_item_pools(&settings, 7);
+    assert_eq!(hearts(&placed), hearts(&before.progression) + hearts(&before.trash) + 6);
+    let verification = verify_placement_with(&settings, &options, &placement).unwrap();
+    assert!(verification.valid, "{:?}", verification.violations);
+}
+
+#[test]
+fn pools_too_big_for_the_checks_are_rejected() {
+    let settings = load_settings("normal");
+    let options = Options {
+        pool_edits: vec![PoolEdit::Add { items: vec![FillerItem::HeartContainer01; 1000], progression: false }],
+        ..Options::default()
+    };
+    assert!(matches!(compute_item_pools_with(&settings, &options, 7), Err(Error::InvalidOptions { .. })));
+}
+
+#[test]
+fn edits_must_leave_the_seed_beatable() {
+    let settings = load_settings("normal");
+    let progression = compute_item_pools(&settings, 7).progression;
+    let options = Options { pool_edits: vec![PoolEdit::Remove { items: progression }], ..Options::default() };
+    assert!(matches!(compute_item_pools_with(&settings, &options, 7), Err(Error::InvalidOptions { .. })));
+
+    let missing = Options {
+        pool_edits: vec![PoolEdit::Replace { item: FillerItem::Triforce, with: FillerItem::RupeeGreen }],
+        ..Options::default()
+    };
+    assert!(matches!(compute_item_pools_with(&settings, &missing, 7), Err(Error::InvalidOptions { .. })));
+}
+
+#[test]
+fn tracker_keeps_its_pool_when_an_edit_is_rejected() {
+    let mut tracker = Tracker::new(load_settings("normal"), 7);
+    let progression = tracker.pools().progression.clone();
+
+    assert!(tracker.edit_pool(vec![PoolEdit::Remove { items: progression.clone() }]).is_err());
+    assert_eq!(tracker.pools().progression, progression);
+    assert!(tracker.options().pool_edits.is_empty());
+
+    tracker.edit_pool(vec![PoolEdit::Add { items: vec![FillerItem::PegasusBoots], progression: false }]).unwrap();
+    assert!(tracker.pools().trash.contains(&FillerItem::PegasusBoots));
+}
@@ -0,0 +1,56 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{prizes::prize_checks, Dungeon, Prize, Tracker, TrackerState};
+
+mod common;
+use common::load_settings;
+
+#[test]
+fn prizes_match_their_items() {
+    for prize in Prize::ALL {
+        assert_eq!(Prize::of_item(prize.item()), Some(prize));
+    }
+    assert_eq!(Prize::ALL.iter().filter(|prize| prize.is_sage()).count(), 7);
+}
+
+#[test]
+fn every_dungeon_but_lorule_castle_has_a_prize_check() {
+    let checks = prize_checks();
+    for dungeon in Dungeon::ALL {
+        assert_eq!(checks.contains_key(&dungeon), dungeon != Dungeon::LoruleCastle, "{:?}", dungeon);
+    }
+}
+
+#[test]
+fn recorded_prizes_go_on_the_prize_checks() {
+    let tracker = Tracker::new(load_settings("normal"), 7);
+    let state: TrackerState = serde_json::from_str(r#"{ "prizes": { "Eastern": "SageImpa", "Turtle": "PendantOfCourage" } }"#).unwrap();
+    let check_map = tracker.check_map(&state);
+    let checks = prize_checks();
+
+    assert_eq!(check_map[checks[&Dungeon::Eastern]], Some(Prize::SageImpa.item()));
+    assert_eq!(check_map[checks[&Dungeon::Turtle]], Some(Prize::PendantOfCourage.item()));
+    assert_eq!(check_map[checks[&Dungeon::Gales]], Some(Prize::PendantOfWisdom.item()));
+}
+
+#[test]
+fn a_single_recorded_prize_swaps_with_its_vanilla_dungeon() {
+    let tracker = Tracker::new(load_settings("normal"), 7);
+    let state: TrackerState = serde_json::from_str(r#"{ "prizes": { "Eastern": "SageImpa" } }"#).unwrap();
+    let check_map = tracker.check_map(&state);
+    let checks = prize_checks();
+
+    assert_eq!(check_map[checks[&Dungeon::Eastern]], Some(Prize::SageImpa.item()));
+    assert_eq!(check_map[checks[&Dungeon::Turtle]], Some(Prize::PendantOfCourage.item()));
+    for prize in Prize::ALL {
+        assert_eq!(check_map.values().filter(|item| **item == Some(prize.item())).count(), 1, "{:?}", prize);
+    }
+}
+
+#[test]
+fn required_dungeons_are_prize_dungeons() {
+    let tracker = Tracker::new(load_settings("normal"), 7);
+    let required = tracker.required_dungeons(&TrackerState::default());
+    assert!(!required.is_empty());
+    assert!(!required.contains(&Dungeon::LoruleCastle));
+}
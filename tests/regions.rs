@@ -0,0 +1,30 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{default_regions, find_barren_regions, find_required_items, generate_assumed_fill, Error, Options, RegionMap};
+
+mod common;
+use common::load_settings;
+
+#[test]
+fn barren_regions_hold_no_required_items() {
+    let settings = load_settings("normal");
+    let placement = generate_assumed_fill(&settings, 4).unwrap();
+    let regions = default_regions();
+    let reports = find_barren_regions(&settings, &Options::default(), &placement, &regions).unwrap();
+    assert_eq!(reports.len(), regions.len());
+
+    let required = find_required_items(&settings, &Options::default(), &placement).unwrap();
+    for report in reports {
+        let checks = &regions[&report.region];
+        assert_eq!(report.barren, !required.iter().any(|x| checks.contains(&x.check)), "{}", report.region);
+    }
+}
+
+#[test]
+fn unknown_checks_in_regions_are_rejected() {
+    let settings = load_settings("normal");
+    let placement = generate_assumed_fill(&settings, 4).unwrap();
+    let regions: RegionMap = [("Nowhere".to_string(), vec!["Not A Check".to_string()])].into_iter().collect();
+
+    assert_eq!(find_barren_regions(&settings, &Options::default(), &placement, &regions), Err(Error::UnknownCheck { name: "Not A Check".to_string() }));
+}
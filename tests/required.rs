@@ -0,0 +1,20 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{find_required_items, generate_assumed_fill, Goal, Options};
+
+mod common;
+use common::load_settings;
+
+#[test]
+fn removing_a_required_item_makes_the_seed_unbeatable() {
+    let settings = load_settings("normal");
+    let placement = generate_assumed_fill(&settings, 3).unwrap();
+    let required = find_required_items(&settings, &Options::default(), &placement).unwrap();
+    assert!(!required.is_empty());
+
+    for item in &required {
+        let mut check_map = placement.check_map();
+        *check_map.get_mut(item.check.as_str()).unwrap() = None;
+        assert!(!Goal::default().is_beatable(&settings, &check_map, &Vec::new()), "{} at {} is not required", item.item, item.check);
+    }
+}
@@ -0,0 +1,58 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{
+    compute_item_pools, compute_playthrough, generate_assumed_fill_with, verify_placement_with, FillerItem, Options, Tracker,
+    TrackerState,
+};
+
+mod common;
+use common::load_settings;
+
+#[test]
+fn starting_items_leave_the_pools() {
+    let options = Options { starting_items: vec![FillerItem::PegasusBoots, FillerItem::RaviosBracelet01], ..Options::default() };
+    let tracker = Tracker::with_options(load_settings("normal"), options, 7).unwrap();
+    let before = compute_item_pools(tracker.settings(), 7);
+
+    for item in [FillerItem::PegasusBoots, FillerItem::RaviosBracelet01] {
+        let count = |pool: &Vec<FillerItem>| pool.iter().filter(|x| **x == item).count();
+        assert_eq!(
+            count(&tracker.pools().progression) + count(&tracker.pools().trash) + 1,
+            count(&before.progression) + count(&before.trash),
+        );
+    }
+}
+
+#[test]
+fn starting_items_count_towards_reachability() {
+    let settings = load_settings("normal");
+    let plain = Tracker::new(load_settings("normal"), 7);
+    let options = Options { starting_items: vec![FillerItem::RaviosBracelet01], ..Options::default() };
+    let bracelet = Tracker::with_options(settings, options, 7).unwrap();
+
+    let state = TrackerState::default();
+    assert_eq!(bracelet.available_checks(&state), plain.reachable_checks(&vec![FillerItem::RaviosBracelet01]));
+    assert!(bracelet.available_checks(&state).len() >= plain.available_checks(&state).len());
+}
+
+#[test]
+fn placements_with_starting_items_verify() {
+    let settings = load_settings("normal");
+    let options = Options { starting_items: vec![FillerItem::PegasusBoots], ..Options::default() };
+
+    let placement = generate_assumed_fill_with(&settings, &options, 7).unwrap();
+    assert!(!placement.checks.iter().any(|x| x.item == FillerItem::PegasusBoots));
+    let verification = verify_placement_with(&settings, &options, &placement).unwrap();
+    assert!(verification.valid, "{:?}", verification.violations);
+}
+
+#[test]
+fn starting_items_are_owned_in_the_playthrough() {
+    let settings = load_settings("normal");
+    let options = Options { starting_items: vec![FillerItem::PegasusBoots], ..Options::default() };
+    let placement = generate_assumed_fill_with(&settings, &options, 7).unwrap();
+
+    let playthrough = compute_playthrough(&settings, &options, &placement).unwrap();
+    assert!(playthrough.beatable);
+    assert!(!playthrough.spheres.iter().flatten().any(|x| x.item == FillerItem::PegasusBoots));
+}
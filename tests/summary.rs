@@ -0,0 +1,56 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{find_dungeon_keys, summarize_dungeons, Dungeon, FillerItem, Prize, Tracker, TrackerState};
+
+mod common;
+use common::load_settings;
+
+#[test]
+fn summaries_count_checked_and_reachable_checks() {
+    let tracker = Tracker::new(load_settings("normal"), 7);
+    let mut state = TrackerState::default();
+    let before = summarize_dungeons(&tracker, &state);
+    assert_eq!(before.iter().map(|x| x.dungeon).collect::<Vec<_>>(), Dungeon::ALL.to_vec());
+    assert!(before.iter().all(|x| x.total_checks > 0 && x.checked == 0 && x.reachable <= x.total_checks));
+
+    let dungeon_check = tracker.available_checks(&state).into_iter().find(|check| Dungeon::of_check(check).is_some());
+    if let Some(check) = dungeon_check {
+        let dungeon = Dungeon::of_check(&check).unwrap();
+        state.checked.insert(check);
+        let after = summarize_dungeons(&tracker, &state);
+        let (old, new) = (before.iter().find(|x| x.dungeon == dungeon).unwrap(), after.iter().find(|x| x.dungeon == dungeon).unwrap());
+        assert_eq!(new.checked, 1);
+        assert_eq!(new.reachable + 1, old.reachable);
+    }
+}
+
+#[test]
+fn summaries_report_dungeon_items() {
+    let tracker = Tracker::new(load_settings("normal"), 7);
+    let state = TrackerState { items: vec![FillerItem::EasternKeyBig, FillerItem::GalesCompass], ..TrackerState::default() };
+    let summaries = summarize_dungeons(&tracker, &state);
+
+    let eastern = summaries.iter().find(|x| x.dungeon == Dungeon::Eastern).unwrap();
+    assert_eq!((eastern.big_key, eastern.compass), (Some(true), Some(false)));
+    let gales = summaries.iter().find(|x| x.dungeon == Dungeon::Gales).unwrap();
+    assert_eq!((gales.big_key, gales.compass), (Some(false), Some(true)));
+    assert!(summaries.iter().all(|x| x.prize.is_none()));
+
+    let lorule = summaries.iter().find(|x| x.dungeon == Dungeon::LoruleCastle).unwrap();
+    assert_eq!(lorule.big_key, None);
+}
+
+#[test]
+fn summaries_take_keys_and_prizes_from_the_state() {
+    let tracker = Tracker::new(load_settings("normal"), 7);
+    let mut state = TrackerState { items: tracker.pools().progression.clone(), ..TrackerState::default() };
+    state.prizes.insert(Dungeon::Eastern, Prize::SageImpa);
+    let summaries = summarize_dungeons(&tracker, &state);
+
+    for (summary, keys) in summaries.iter().zip(find_dungeon_keys(&tracker, &state)) {
+        assert_eq!((summary.keys_found, summary.keys_needed), (keys.keys_found, keys.keys_needed));
+    }
+    let eastern = summaries.iter().find(|x| x.dungeon == Dungeon::Eastern).unwrap();
+    assert_eq!(eastern.prize.as_deref(), Some("SageImpa"));
+    assert!(summaries.iter().filter(|x| x.dungeon != Dungeon::Eastern).all(|x| x.prize.is_none()));
+}
@@ -0,0 +1,51 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{FillerItem, HintFact, Relevance, Tracker, TrackerState};
+
+mod common;
+use common::load_settings;
+
+#[test]
+fn tracker_state_round_trips_through_item_names() {
+    let json = r#"{ "items": ["Bow01", "PegasusBoots"], "checked": ["Ravio (1)"] }"#;
+    let state: TrackerState = serde_json::from_str(json).unwrap();
+    assert_eq!(state.items, vec![FillerItem::Bow01, FillerItem::PegasusBoots]);
+
+    let back: TrackerState = serde_json::from_str(&serde_json::to_string(&state).unwrap()).unwrap();
+    assert_eq!(back, state);
+}
+
+#[test]
+fn tracker_state_rejects_unknown_items() {
+    assert!(serde_json::from_str::<TrackerState>(r#"{ "items": ["MasterSword"] }"#).is_err());
+}
+
+#[test]
+fn available_checks_skip_checked_ones() {
+    let tracker = Tracker::new(load_settings("normal"), 1);
+    let mut state = TrackerState::default();
+    let reachable = tracker.reachable_checks(&state.items);
+    assert!(!reachable.is_empty());
+
+    state.checked.insert(reachable[0].clone());
+    let available = tracker.available_checks(&state);
+    assert_eq!(available.len(), reachable.len() - 1);
+    assert!(!available.contains(&reachable[0]));
+}
+
+#[test]
+fn hints_flag_available_checks() {
+    let tracker = Tracker::new(load_settings("normal"), 1);
+    let mut state = TrackerState::default();
+    let available = tracker.available_checks(&state);
+    let overworld = &tracker.regions()[albw_wasm::regions::OVERWORLD];
+
+    state.hints.push(HintFact::Barren { region: albw_wasm::regions::OVERWORLD.to_string() });
+    for check in tracker.annotated_checks(&state) {
+        let expected = if overworld.contains(&check.check) { Relevance::Irrelevant } else { Relevance::Unknown };
+        assert_eq!(check.relevance, expected, "{}", check.check);
+    }
+
+    state.hints.push(HintFact::ItemAtCheck { item: FillerItem::Bow01, check: available[0].clone() });
+    assert_eq!(tracker.relevance(&state, &available[0]), Relevance::Relevant);
+}
@@ -0,0 +1,25 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+// The typescript_custom_section literal can't be computed at compile time, so
+// this keeps the copy in src/typescript.rs equal to what declarations() renders.
+
+use std::{fs, path::PathBuf};
+
+const START: &str = "const TYPESCRIPT_DECLARATIONS: &str = r#\"";
+const END: &str = "\"#;";
+
+#[test]
+fn embedded_declarations_are_up_to_date() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/typescript.rs");
+    let source = fs::read_to_string(&path).unwrap();
+    let start = source.find(START).expect("TYPESCRIPT_DECLARATIONS not found") + START.len();
+    let end = start + source[start..].find(END).unwrap();
+
+    let expected = albw_wasm::typescript::declarations();
+    if std::env::var_os("UPDATE_TYPESCRIPT").is_some() {
+        fs::write(&path, format!("{}{}{}", &source[..start], expected, &source[end..])).unwrap();
+        return;
+    }
+
+    assert!(source[start..end] == expected, "TypeScript declarations are out of date, rerun with UPDATE_TYPESCRIPT=1");
+}
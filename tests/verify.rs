@@ -0,0 +1,29 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{generate_assumed_fill, verify_placement, FillerItem, PlacedItem, Violation};
+
+mod common;
+use common::load_settings;
+
+#[test]
+fn generated_placements_verify() {
+    let settings = load_settings("normal");
+    let placement = generate_assumed_fill(&settings, 30).unwrap();
+    let verification = verify_placement(&settings, &placement);
+    assert!(verification.valid, "{:?}", verification.violations);
+}
+
+#[test]
+fn edited_placements_report_precise_violations() {
+    let settings = load_settings("normal");
+    let mut placement = generate_assumed_fill(&settings, 30).unwrap();
+
+    let removed = placement.checks.iter().position(|x| x.item == FillerItem::HeartPiece01).unwrap();
+    let removed = placement.checks.remove(removed);
+    placement.checks.push(PlacedItem { check: "Not A Check".to_string(), item: FillerItem::HeartPiece02 });
+
+    let violations = verify_placement(&settings, &placement).violations;
+    assert!(violations.contains(&Violation::EmptyCheck { check: removed.check }));
+    assert!(violations.contains(&Violation::UnknownCheck { check: "Not A Check".to_string() }));
+    assert!(violations.contains(&Violation::MissingItem { item: "HeartPiece01".to_string(), count: 1 }));
+}
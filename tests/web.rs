@@ -0,0 +1,342 @@
+// Exercises the JS boundary of the wasm exports. Runs headless under Node:
+//
+//     wasm-pack test --node
+
+#![cfg(target_arch = "wasm32")]
+
+use albw_wasm::{
+    typescript::{JsPlacement, JsSettings, JsTrackerState},
+    wasm::{analyze_seeds, verify_placement},
+    Cartridge, WorkerHost,
+};
+use js_sys::{Array, Object, JSON, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::*;
+
+const SETTINGS: &str = r#"{
+    "logic": {
+        "mode": "Normal",
+        "super_items": false,
+        "skip_trials": false,
+        "lampless": false,
+        "swordless_mode": false
+    }
+}"#;
+
+fn settings() -> JsSettings {
+    JSON::parse(SETTINGS).unwrap().unchecked_into()
+}
+
+fn cartridge() -> Cartridge {
+    Cartridge::new(settings(), 1337, None).unwrap()
+}
+
+fn parse<T: JsCast>(json: &str) -> T {
+    JSON::parse(json).unwrap().unchecked_into()
+}
+
+fn empty_state() -> JsTrackerState {
+    parse("{}")
+}
+
+fn placement(cartridge: &Cartridge) -> JsPlacement {
+    cartridge.generate_assumed_fill().unwrap()
+}
+
+fn to_strings(value: &JsValue) -> Vec<String> {
+    assert!(Array::is_array(value), "expected an array, got {:?}", value);
+    Array::from(value).iter().map(|x| x.as_string().expect("expected a string")).collect()
+}
+
+fn get(value: &JsValue, key: &str) -> JsValue {
+    Reflect::get(value, &JsValue::from_str(key)).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn constructs_from_plain_js_object() {
+    assert_eq!(cartridge().get_seed(), 1337);
+}
+
+#[wasm_bindgen_test]
+fn rejects_malformed_settings() {
+    let error = Cartridge::new(JsValue::from_str("not settings").unchecked_into(), 1, None).err().unwrap();
+    assert_eq!(get(&error, "kind").as_string().unwrap(), "InvalidSettings");
+}
+
+#[wasm_bindgen_test]
+fn item_name_lists_are_sorted_string_arrays() {
+    let cartridge = cartridge();
+    for names in [to_strings(&cartridge.get_progression_item_names()), to_strings(&cartridge.get_trash_item_names())] {
+        assert!(!names.is_empty());
+        let mut sorted = names.clone();
+        sorted.sort_by(|a, b| a.to_lowercase().cmp(&b.to_lowercase()));
+        assert_eq!(names, sorted);
+    }
+}
+
+#[wasm_bindgen_test]
+fn item_pool_combines_both_halves() {
+    let cartridge = cartridge();
+    let progression = to_strings(&cartridge.get_progression_item_names());
+    let trash = to_strings(&cartridge.get_trash_item_names());
+
+    let pool = Array::from(&cartridge.get_item_pool());
+    let mut total = 0;
+    for entry in pool.iter() {
+        let name = get(&entry, "name").as_string().unwrap();
+        let count = get(&entry, "count").as_f64().unwrap() as usize;
+        let expected = if get(&entry, "progression").as_bool().unwrap() { &progression } else { &trash };
+        assert_eq!(expected.iter().filter(|x| **x == name).count(), count, "count of {}", name);
+        total += count;
+    }
+    assert_eq!(total, progression.len() + trash.len());
+}
+
+#[wasm_bindgen_test]
+fn pool_entries_outside_dungeons_have_a_null_dungeon() {
+    let pool = Array::from(&cartridge().get_item_pool());
+    let bow = pool.iter().find(|entry| get(entry, "name").as_string().unwrap() == "Bow01").unwrap();
+    assert!(get(&bow, "dungeon").is_null());
+
+    for entry in pool.iter() {
+        let dungeon = get(&entry, "dungeon");
+        assert!(dungeon.is_null() || dungeon.as_string().is_some(), "{:?}", entry);
+    }
+}
+
+#[wasm_bindgen_test]
+fn available_checks_grow_with_items() {
+    let cartridge = cartridge();
+    let nothing = to_strings(&cartridge.get_available_checks(Array::new().unchecked_into()).unwrap());
+    let everything = to_strings(&cartridge.get_available_checks(cartridge.get_progression_item_names()).unwrap());
+
+    assert!(!nothing.is_empty());
+    assert!(nothing.len() < everything.len());
+    assert!(nothing.iter().all(|check| everything.contains(check)));
+}
+
+#[wasm_bindgen_test]
+fn available_checks_rejects_unknown_item_names() {
+    let items = Array::of2(&JsValue::from_str("Bow01"), &JsValue::from_str("MasterSword"));
+    let error = cartridge().get_available_checks(items.unchecked_into()).err().unwrap();
+
+    assert_eq!(get(&error, "kind").as_string().unwrap(), "UnknownItem");
+    assert_eq!(get(&error, "name").as_string().unwrap(), "MasterSword");
+}
+
+#[wasm_bindgen_test]
+fn available_checks_rejects_non_array_input() {
+    let error = cartridge().get_available_checks(JsValue::from_f64(3.0).unchecked_into()).err().unwrap();
+    assert_eq!(get(&error, "kind").as_string().unwrap(), "InvalidArgument");
+}
+
+#[wasm_bindgen_test]
+fn edit_item_pool_changes_the_listings() {
+    let mut cartridge = cartridge();
+    let trash = to_strings(&cartridge.get_trash_item_names());
+    let edits = Array::of1(&JSON::parse(&format!(r#"{{ "type": "remove", "items": ["{}"] }}"#, trash[0])).unwrap());
+    cartridge.edit_item_pool(edits.unchecked_into()).unwrap();
+
+    assert_eq!(to_strings(&cartridge.get_trash_item_names()).len(), trash.len() - 1);
+}
+
+#[wasm_bindgen_test]
+fn generated_fill_is_a_placement_object() {
+    let cartridge = cartridge();
+    let placement: JsValue = placement(&cartridge).into();
+
+    assert_eq!(get(&placement, "seed").as_f64().unwrap(), 1337.0);
+    let checks = Array::from(&get(&placement, "checks"));
+    assert!(checks.length() > 0);
+    assert!(get(&checks.get(0), "item").as_string().is_some());
+}
+
+#[wasm_bindgen_test]
+fn generated_fill_verifies() {
+    let cartridge = cartridge();
+    let verification: JsValue = verify_placement(settings(), placement(&cartridge), None).unwrap().into();
+
+    assert!(get(&verification, "valid").as_bool().unwrap());
+    assert_eq!(Array::from(&get(&verification, "violations")).length(), 0);
+}
+
+#[wasm_bindgen_test]
+fn verify_placement_reports_violations_as_objects() {
+    let placement = parse(r#"{ "seed": 1337, "checks": [] }"#);
+    let verification: JsValue = verify_placement(settings(), placement, None).unwrap().into();
+
+    assert!(!get(&verification, "valid").as_bool().unwrap());
+    let violation = Array::from(&get(&verification, "violations")).get(0);
+    assert!(get(&violation, "kind").as_string().is_some());
+}
+
+#[wasm_bindgen_test]
+fn playthrough_of_a_generated_fill_is_beatable() {
+    let cartridge = cartridge();
+    let playthrough: JsValue = cartridge.get_playthrough(placement(&cartridge)).unwrap().into();
+
+    assert!(get(&playthrough, "beatable").as_bool().unwrap());
+    assert!(Array::from(&get(&playthrough, "spheres")).length() > 0);
+}
+
+#[wasm_bindgen_test]
+fn hints_have_ghosts_and_text() {
+    let cartridge = cartridge();
+    let hints: JsValue = cartridge.get_hints(placement(&cartridge), None, None).unwrap().into();
+    let hints = Array::from(&hints);
+
+    assert!(hints.length() > 0);
+    for hint in hints.iter() {
+        assert!(!get(&hint, "ghost").as_string().unwrap().is_empty());
+        assert!(!get(&hint, "text").as_string().unwrap().is_empty());
+    }
+}
+
+#[wasm_bindgen_test]
+fn dungeon_summaries_cover_every_dungeon() {
+    let summaries: JsValue = cartridge().get_dungeon_summaries(empty_state()).unwrap().into();
+    let summaries = Array::from(&summaries);
+
+    assert_eq!(summaries.length(), 11);
+    for summary in summaries.iter() {
+        assert!(get(&summary, "dungeon").as_string().is_some());
+        assert_eq!(get(&summary, "checked").as_f64().unwrap(), 0.0);
+    }
+}
+
+#[wasm_bindgen_test]
+fn goal_status_starts_closed() {
+    let status: JsValue = cartridge().get_goal_status(empty_state()).unwrap().into();
+
+    assert_eq!(get(&status, "sages").as_f64().unwrap(), 0.0);
+    assert!(!get(&status, "lorule_castle_open").as_bool().unwrap());
+    assert!(!get(&status, "triforce_reachable").as_bool().unwrap());
+}
+
+#[wasm_bindgen_test]
+fn analyze_seeds_counts_the_range() {
+    let analysis: JsValue = analyze_seeds(settings(), parse(r#"{ "start": 1, "end": 3 }"#), None).unwrap().into();
+
+    assert_eq!(get(&analysis, "seeds").as_f64().unwrap(), 2.0);
+    let generated = get(&analysis, "generated").as_f64().unwrap() as u32;
+    assert_eq!(generated + Array::from(&get(&analysis, "failures")).length(), 2);
+}
+
+#[wasm_bindgen_test]
+fn analyze_seeds_takes_options() {
+    let options = parse(r#"{ "dungeon_items": "removed" }"#);
+    let analysis: JsValue = analyze_seeds(settings(), parse(r#"{ "start": 1, "end": 2 }"#), Some(options)).unwrap().into();
+
+    let items = Object::keys(&get(&analysis, "item_locations").unchecked_into());
+    assert!(!items.iter().any(|item| item.as_string().unwrap().contains("KeySmall")));
+}
+
+#[wasm_bindgen_test]
+fn default_regions_map_names_to_check_lists() {
+    let regions: JsValue = cartridge().get_default_regions().into();
+    let names = Object::keys(&regions.clone().unchecked_into());
+
+    assert!(names.length() > 0);
+    for name in names.iter() {
+        assert!(!to_strings(&get(&regions, &name.as_string().unwrap())).is_empty());
+    }
+}
+
+#[wasm_bindgen_test]
+fn annotated_checks_follow_hints_in_set_regions() {
+    let mut cartridge = cartridge();
+    let plain: JsValue = cartridge.get_annotated_checks(empty_state()).unwrap().into();
+    let plain = Array::from(&plain);
+    assert!(plain.length() > 0);
+    assert!(plain.iter().all(|x| get(&x, "relevance").as_string().unwrap() == "unknown" && !get(&x, "junk_only").as_bool().unwrap()));
+
+    let check = get(&plain.get(0), "check").as_string().unwrap();
+    cartridge.set_regions(parse(&format!(r#"{{ "Test Region": ["{}"] }}"#, check))).unwrap();
+    let state = parse(r#"{ "hints": [{ "type": "barren", "region": "Test Region" }] }"#);
+    let hinted: JsValue = cartridge.get_annotated_checks(state).unwrap().into();
+    let hinted = Array::from(&hinted).iter().find(|x| get(x, "check").as_string().unwrap() == check).unwrap();
+    assert_eq!(get(&hinted, "relevance").as_string().unwrap(), "irrelevant");
+}
+
+#[wasm_bindgen_test]
+fn set_regions_rejects_unknown_checks() {
+    let error = cartridge().set_regions(parse(r#"{ "Nowhere": ["Not A Check"] }"#)).err().unwrap();
+    assert_eq!(get(&error, "kind").as_string().unwrap(), "UnknownCheck");
+}
+
+#[wasm_bindgen_test]
+fn dungeon_keys_start_at_zero() {
+    let keys: JsValue = cartridge().get_dungeon_keys(empty_state()).unwrap().into();
+    let keys = Array::from(&keys);
+
+    assert_eq!(keys.length(), 11);
+    for dungeon in keys.iter() {
+        assert_eq!(get(&dungeon, "keys_found").as_f64().unwrap(), 0.0);
+        assert!(get(&dungeon, "keys_total").as_f64().is_some());
+        assert!(!get(&dungeon, "soft_lock_risk").as_bool().unwrap());
+    }
+}
+
+#[wasm_bindgen_test]
+fn required_dungeons_are_dungeon_names() {
+    let dungeons = to_strings(&cartridge().get_required_dungeons(empty_state()).unwrap().into());
+    assert!(!dungeons.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn difficulty_of_a_generated_fill_has_a_score() {
+    let cartridge = cartridge();
+    let difficulty: JsValue = cartridge.get_difficulty(placement(&cartridge)).unwrap().into();
+
+    assert!(get(&difficulty, "spheres").as_f64().unwrap() > 0.0);
+    assert!(get(&difficulty, "score").as_f64().is_some());
+    assert!(Array::is_array(&get(&difficulty, "key_items")));
+}
+
+#[wasm_bindgen_test]
+fn required_items_name_items_and_checks() {
+    let cartridge = cartridge();
+    let required: JsValue = cartridge.get_required_items(placement(&cartridge)).unwrap().into();
+    let required = Array::from(&required);
+
+    assert!(required.length() > 0);
+    for item in required.iter() {
+        assert!(get(&item, "item").as_string().is_some());
+        assert!(get(&item, "check").as_string().is_some());
+    }
+}
+
+#[wasm_bindgen_test]
+fn barren_regions_default_to_the_default_regions() {
+    let cartridge = cartridge();
+    let reports: JsValue = cartridge.get_barren_regions(placement(&cartridge), None).unwrap().into();
+    let regions = Object::keys(&cartridge.get_default_regions().unchecked_into());
+    assert_eq!(Array::from(&reports).length(), regions.length());
+
+    let custom = parse(r#"{ "Nothing": [] }"#);
+    let reports: JsValue = cartridge.get_barren_regions(placement(&cartridge), Some(custom)).unwrap().into();
+    let report = Array::from(&reports).get(0);
+    assert_eq!(get(&report, "region").as_string().unwrap(), "Nothing");
+    assert!(get(&report, "barren").as_bool().unwrap());
+}
+
+#[wasm_bindgen_test]
+fn worker_host_answers_with_plain_objects() {
+    let mut host = WorkerHost::new();
+    let init = JSON::parse(&format!(r#"{{ "id": 1, "type": "init", "seed": 3, "settings": {} }}"#, SETTINGS)).unwrap();
+    let ready: JsValue = host.handle(init.unchecked_into()).into();
+    assert_eq!(get(&ready, "type").as_string().unwrap(), "ready");
+
+    let query = JSON::parse(r#"{ "id": 2, "type": "available_checks", "items": ["Bow01"] }"#).unwrap();
+    let response: JsValue = host.handle(query.unchecked_into()).into();
+    assert_eq!(get(&response, "id").as_f64().unwrap(), 2.0);
+    assert!(!to_strings(&get(&response, "checks")).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn worker_host_reports_malformed_requests_with_their_id() {
+    let mut host = WorkerHost::new();
+    let response: JsValue = host.handle(JSON::parse(r#"{ "id": 9, "type": "launch_missiles" }"#).unwrap().unchecked_into()).into();
+    assert_eq!(get(&response, "id").as_f64().unwrap(), 9.0);
+    assert_eq!(get(&get(&response, "error"), "kind").as_string().unwrap(), "InvalidArgument");
+}
@@ -0,0 +1,84 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use albw_wasm::{
+    find_dungeon_keys, summarize_dungeons,
+    worker::{Reply, Request, Session},
+    Options, Tracker, TrackerState,
+};
+use serde_json::{json, Value};
+
+mod common;
+
+fn request(value: Value) -> Request {
+    serde_json::from_value(value).unwrap()
+}
+
+fn init(session: &mut Session) {
+    let settings: Value = serde_json::from_str(&common::settings_json("normal")).unwrap();
+    let response = session.handle(request(json!({ "id": 1, "type": "init", "settings": settings, "seed": 5 })));
+    assert!(matches!(response.reply, Reply::Ready { seed: 5 }));
+}
+
+#[test]
+fn responses_carry_the_request_id() {
+    let mut session = Session::new();
+    init(&mut session);
+
+    let response = session.handle(request(json!({ "id": 42, "type": "available_checks", "items": [] })));
+    assert_eq!(response.id, 42);
+    assert!(matches!(response.reply, Reply::AvailableChecks { .. }));
+
+    let json = serde_json::to_value(&response).unwrap();
+    assert_eq!(json["id"], 42);
+    assert_eq!(json["type"], "available_checks");
+}
+
+#[test]
+fn requests_before_init_are_errors() {
+    let response = Session::new().handle(request(json!({ "id": 3, "type": "item_pool" })));
+    assert_eq!(response.id, 3);
+    assert!(matches!(response.reply, Reply::Error { .. }));
+}
+
+#[test]
+fn unknown_items_are_reported_as_errors() {
+    let mut session = Session::new();
+    init(&mut session);
+
+    let response = session.handle(request(json!({ "id": 4, "type": "available_checks", "items": ["MasterSword"] })));
+    let json = serde_json::to_value(&response).unwrap();
+    assert_eq!(json["type"], "error");
+    assert_eq!(json["error"]["kind"], "UnknownItem");
+}
+
+#[test]
+fn state_commands_match_the_tracker() {
+    let mut session = Session::new();
+    init(&mut session);
+    let tracker = Tracker::with_options(common::load_settings("normal"), Options::default(), 5).unwrap();
+
+    let state_json = json!({ "items": ["Bow01", "Bombs01"], "checked": [], "small_keys": { "Eastern": 1 }, "prizes": { "Eastern": "PendantOfCourage" } });
+    let state: TrackerState = serde_json::from_value(state_json.clone()).unwrap();
+    let mut send = |kind: &str| session.handle(request(json!({ "id": 6, "type": kind, "state": state_json }))).reply;
+
+    match send("annotated_checks") {
+        Reply::AnnotatedChecks { annotated_checks } => assert_eq!(annotated_checks, tracker.annotated_checks(&state)),
+        other => panic!("unexpected reply {:?}", other),
+    }
+    match send("dungeon_keys") {
+        Reply::DungeonKeys { keys } => assert_eq!(keys, find_dungeon_keys(&tracker, &state)),
+        other => panic!("unexpected reply {:?}", other),
+    }
+    match send("dungeon_summaries") {
+        Reply::DungeonSummaries { summaries } => assert_eq!(summaries, summarize_dungeons(&tracker, &state)),
+        other => panic!("unexpected reply {:?}", other),
+    }
+    match send("required_dungeons") {
+        Reply::RequiredDungeons { dungeons } => assert_eq!(dungeons, tracker.required_dungeons(&state)),
+        other => panic!("unexpected reply {:?}", other),
+    }
+    match send("goal_status") {
+        Reply::GoalStatus { status } => assert_eq!(status, tracker.goal_status(&state)),
+        other => panic!("unexpected reply {:?}", other),
+    }
+}